@@ -0,0 +1,152 @@
+//! Compares the peak stack usage of the by-value utilities with their in-place counterparts.
+//!
+//! Every measurement operates on a heap allocated 4 KiB buffer, so that only the stack used by
+//! the utility itself is measured. The stack usage is determined by painting the unused part of
+//! the stack with a known pattern before calling the utility and checking how much of the pattern
+//! has been overwritten afterwards. Both numbers include the small, constant overhead of
+//! allocating the buffer.
+//!
+//! Run with `cargo bench --bench stack_usage`.
+
+use std::hint::black_box;
+use std::ptr;
+use std::thread;
+
+use array_utils::{
+    array_resize, array_resize_into, drift_to_begin, drift_to_begin_in_place, drift_to_end,
+    drift_to_end_in_place, superimpose, superimpose_in_place,
+};
+
+const BUFFER_SIZE: usize = 4 * 1024;
+const PAINT: u8 = 0xA5;
+const PAINTED_BYTES: usize = 64 * 1024;
+const STACK_SIZE: usize = 1024 * 1024;
+
+type Buffer = Box<[u8; BUFFER_SIZE]>;
+type ResizedBuffer = Box<[u8; BUFFER_SIZE + 256]>;
+
+#[inline(never)]
+fn stack_address() -> usize {
+    let marker = 0u8;
+    black_box(&marker) as *const u8 as usize
+}
+
+/// Measure the amount of stack bytes used by `f`.
+fn peak_stack_usage<F: FnOnce() + Send + 'static>(f: F) -> usize {
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let top = stack_address();
+            let bottom = top - PAINTED_BYTES;
+
+            // SAFETY: The painted region lies within the stack of this thread, below all frames
+            // that are currently in use.
+            unsafe {
+                for address in bottom..top {
+                    ptr::write_volatile(address as *mut u8, PAINT);
+                }
+            }
+
+            f();
+
+            // SAFETY: See above.
+            let untouched = (bottom..top)
+                .take_while(|&address| unsafe { ptr::read_volatile(address as *const u8) } == PAINT)
+                .count();
+            PAINTED_BYTES - untouched
+        })
+        .expect("failed to spawn measuring thread")
+        .join()
+        .expect("measured function panicked")
+}
+
+fn buffer() -> Buffer {
+    Box::new(std::array::from_fn(|index| index as u8))
+}
+
+#[inline(never)]
+fn by_value_superimpose(buffer: &mut Buffer) {
+    **buffer = superimpose(**buffer, black_box([0xFF; 16]), black_box(100));
+}
+
+#[inline(never)]
+fn in_place_superimpose(buffer: &mut Buffer) {
+    superimpose_in_place(buffer, &black_box([0xFF; 16]), black_box(100));
+}
+
+#[inline(never)]
+fn by_value_drift_to_begin(buffer: &mut Buffer) {
+    **buffer = drift_to_begin(**buffer, black_box(100), black_box(4), 0);
+}
+
+#[inline(never)]
+fn in_place_drift_to_begin(buffer: &mut Buffer) {
+    drift_to_begin_in_place(buffer, black_box(100), black_box(4), 0);
+}
+
+#[inline(never)]
+fn by_value_drift_to_end(buffer: &mut Buffer) {
+    **buffer = drift_to_end(**buffer, black_box(100), black_box(4), 0);
+}
+
+#[inline(never)]
+fn in_place_drift_to_end(buffer: &mut Buffer) {
+    drift_to_end_in_place(buffer, black_box(100), black_box(4), 0);
+}
+
+#[inline(never)]
+fn by_value_array_resize(buffer: &Buffer, output: &mut ResizedBuffer) {
+    **output = array_resize(**buffer, 0);
+}
+
+#[inline(never)]
+fn in_place_array_resize(buffer: &Buffer, output: &mut ResizedBuffer) {
+    array_resize_into(buffer, output, 0);
+}
+
+fn report(name: &str, by_value: usize, in_place: usize) {
+    println!(
+        "{:<16} by value: {:>6} bytes    in place: {:>6} bytes",
+        name, by_value, in_place
+    );
+}
+
+fn main() {
+    println!("Peak stack usage for a {} byte buffer", BUFFER_SIZE);
+
+    let measure = |f: fn(&mut Buffer)| {
+        peak_stack_usage(move || {
+            let mut buffer = buffer();
+            f(&mut buffer);
+            black_box(buffer);
+        })
+    };
+    report(
+        "superimpose",
+        measure(by_value_superimpose),
+        measure(in_place_superimpose),
+    );
+    report(
+        "drift_to_begin",
+        measure(by_value_drift_to_begin),
+        measure(in_place_drift_to_begin),
+    );
+    report(
+        "drift_to_end",
+        measure(by_value_drift_to_end),
+        measure(in_place_drift_to_end),
+    );
+
+    let measure = |f: fn(&Buffer, &mut ResizedBuffer)| {
+        peak_stack_usage(move || {
+            let (buffer, mut output) = (buffer(), Box::new([0; BUFFER_SIZE + 256]));
+            f(&buffer, &mut output);
+            black_box(output);
+        })
+    };
+    report(
+        "array_resize",
+        measure(by_value_array_resize),
+        measure(in_place_array_resize),
+    );
+}
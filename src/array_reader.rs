@@ -0,0 +1,320 @@
+//! A cursor for parsing sized arrays out of a byte buffer.
+//!
+//! See [`ArrayReader`] for more information.
+
+use core::fmt;
+
+/// Error returned by the reads of an [`ArrayReader`] when the input ends too early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadError {
+    /// The read needed `needed` bytes, but only `available` bytes were left.
+    UnexpectedEnd {
+        /// Amount of bytes the read needed
+        needed: usize,
+        /// Amount of bytes that were left to read
+        available: usize,
+    },
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::UnexpectedEnd { needed, available } => write!(
+                f,
+                "unexpected end of input: needed {} bytes, but only {} are left",
+                needed, available
+            ),
+        }
+    }
+}
+
+/// A cursor which reads sized arrays and integers from a byte buffer, front to back.
+///
+/// The inverse of [`ByteWriter`](crate::ByteWriter). Every read advances the cursor past the bytes
+/// it consumed. A read which needs more bytes than remain consumes nothing and returns a
+/// [`ReadError::UnexpectedEnd`], instead of filling up the missing bytes like
+/// [`sized_slice`](crate::sized_slice) would.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::{ArrayReader, ReadError};
+///
+/// let frame = [0x7E, 0x00, 0x02, 0xCA, 0xFE, 0x01];
+///
+/// let mut reader = ArrayReader::new(&frame);
+/// assert_eq!(reader.read_u8()?, 0x7E);
+/// let length = reader.read_u16_be()?;
+/// let mut payload = reader.read_reader(length as usize)?;
+///
+/// assert_eq!(payload.read_array()?, [0xCA, 0xFE]);
+/// assert!(payload.is_empty());
+///
+/// assert_eq!(reader.read_u32_le(), Err(ReadError::UnexpectedEnd { needed: 4, available: 1 }));
+/// assert_eq!(reader.read_u8()?, 0x01);
+/// # Ok::<(), ReadError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrayReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+macro_rules! integer_readers {
+    ($($integer:ty: $big_endian:ident, $little_endian:ident;)*) => {$(
+        #[doc = concat!("Read a [`", stringify!($integer), "`] in big endian byte order.")]
+        pub const fn $big_endian(&mut self) -> Result<$integer, ReadError> {
+            match self.read_array() {
+                Ok(bytes) => Ok(<$integer>::from_be_bytes(bytes)),
+                Err(error) => Err(error),
+            }
+        }
+
+        #[doc = concat!("Read a [`", stringify!($integer), "`] in little endian byte order.")]
+        pub const fn $little_endian(&mut self) -> Result<$integer, ReadError> {
+            match self.read_array() {
+                Ok(bytes) => Ok(<$integer>::from_le_bytes(bytes)),
+                Err(error) => Err(error),
+            }
+        }
+    )*};
+}
+
+impl<'a> ArrayReader<'a> {
+    /// Create a new reader at the start of `bytes`.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        ArrayReader { bytes, position: 0 }
+    }
+
+    /// The amount of bytes read so far, which is the index the next byte is read from.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The amount of bytes that can still be read.
+    pub const fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    /// Whether all bytes have been read.
+    pub const fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// The bytes that have not been read yet.
+    pub const fn rest(&self) -> &'a [u8] {
+        self.bytes.split_at(self.position).1
+    }
+
+    /// Read the next `length` bytes as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::{ArrayReader, ReadError};
+    ///
+    /// let mut reader = ArrayReader::new(b"abcde");
+    /// assert_eq!(reader.read_slice(3), Ok(&b"abc"[..]));
+    /// assert_eq!(
+    ///     reader.read_slice(3),
+    ///     Err(ReadError::UnexpectedEnd { needed: 3, available: 2 })
+    /// );
+    /// assert_eq!(reader.rest(), b"de");
+    /// ```
+    pub const fn read_slice(&mut self, length: usize) -> Result<&'a [u8], ReadError> {
+        let remaining = self.remaining();
+        if length > remaining {
+            return Err(ReadError::UnexpectedEnd {
+                needed: length,
+                available: remaining,
+            });
+        }
+
+        let (slice, _) = self.rest().split_at(length);
+        self.position += length;
+        Ok(slice)
+    }
+
+    /// Read the next `LENGTH` bytes as a sized array.
+    pub const fn read_array<const LENGTH: usize>(&mut self) -> Result<[u8; LENGTH], ReadError> {
+        let slice = match self.read_slice(LENGTH) {
+            Ok(slice) => slice,
+            Err(error) => return Err(error),
+        };
+
+        let mut array = [0; LENGTH];
+        let mut i = 0;
+        while i < LENGTH {
+            array[i] = slice[i];
+            i += 1;
+        }
+        Ok(array)
+    }
+
+    /// Read the next `length` bytes as a separate reader.
+    ///
+    /// This is useful for length-prefixed fields, which are then parsed without being able to
+    /// read past their end.
+    pub const fn read_reader(&mut self, length: usize) -> Result<ArrayReader<'a>, ReadError> {
+        match self.read_slice(length) {
+            Ok(slice) => Ok(ArrayReader::new(slice)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Skip over the next `count` bytes.
+    pub const fn skip(&mut self, count: usize) -> Result<(), ReadError> {
+        match self.read_slice(count) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Read a [`u8`].
+    pub const fn read_u8(&mut self) -> Result<u8, ReadError> {
+        match self.read_array::<1>() {
+            Ok([byte]) => Ok(byte),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Read a [`i8`].
+    pub const fn read_i8(&mut self) -> Result<i8, ReadError> {
+        match self.read_array() {
+            Ok(bytes) => Ok(i8::from_be_bytes(bytes)),
+            Err(error) => Err(error),
+        }
+    }
+
+    integer_readers! {
+        u16: read_u16_be, read_u16_le;
+        u32: read_u32_be, read_u32_le;
+        u64: read_u64_be, read_u64_le;
+        u128: read_u128_be, read_u128_le;
+        i16: read_i16_be, read_i16_le;
+        i32: read_i32_be, read_i32_le;
+        i64: read_i64_be, read_i64_le;
+        i128: read_i128_be, read_i128_le;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ByteWriter;
+
+    #[test]
+    fn integers() {
+        let bytes = [
+            0x01, 0x02, 0x02, 0x01, 0xFF, 0xFF, 0xFF, 0xFE, 1, 2, 3, 4, 5, 6, 7, 8, 0xFF,
+        ];
+        let mut reader = ArrayReader::new(&bytes);
+
+        assert_eq!(reader.read_u16_be(), Ok(0x0102));
+        assert_eq!(reader.read_u16_le(), Ok(0x0102));
+        assert_eq!(reader.read_i32_be(), Ok(-2));
+        assert_eq!(reader.read_u64_le(), Ok(0x0807_0605_0403_0201));
+        assert_eq!(reader.read_i8(), Ok(-1));
+        assert_eq!(
+            reader.read_u8(),
+            Err(ReadError::UnexpectedEnd {
+                needed: 1,
+                available: 0
+            })
+        );
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn too_short() {
+        let mut reader = ArrayReader::new(&[1, 2, 3]);
+        assert_eq!(
+            reader.read_u128_be(),
+            Err(ReadError::UnexpectedEnd {
+                needed: 16,
+                available: 3
+            })
+        );
+        assert_eq!(
+            reader.skip(usize::MAX),
+            Err(ReadError::UnexpectedEnd {
+                needed: usize::MAX,
+                available: 3
+            })
+        );
+        assert_eq!(
+            reader.read_reader(4),
+            Err(ReadError::UnexpectedEnd {
+                needed: 4,
+                available: 3
+            })
+        );
+        assert_eq!(reader.position(), 0);
+
+        assert_eq!(reader.skip(1), Ok(()));
+        assert_eq!(reader.read_array(), Ok([2, 3]));
+        assert_eq!(reader.read_array(), Ok([]));
+        assert_eq!(reader.read_slice(0), Ok(&[][..]));
+        assert_eq!(
+            reader.read_array::<1>(),
+            Err(ReadError::UnexpectedEnd {
+                needed: 1,
+                available: 0
+            })
+        );
+
+        let mut empty = ArrayReader::new(&[]);
+        assert_eq!(
+            empty.read_u8(),
+            Err(ReadError::UnexpectedEnd {
+                needed: 1,
+                available: 0
+            })
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let mut reader = ArrayReader::new(&[2, 0xAB, 0xCD, 9]);
+        let length = reader.read_u8().unwrap();
+        let mut field = reader.read_reader(length as usize).unwrap();
+
+        assert_eq!(field.read_u8(), Ok(0xAB));
+        assert_eq!(
+            field.read_u16_be(),
+            Err(ReadError::UnexpectedEnd {
+                needed: 2,
+                available: 1
+            })
+        );
+        assert_eq!(field.rest(), [0xCD]);
+        assert_eq!(reader.read_u8(), Ok(9));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut writer: ByteWriter<16> = ByteWriter::new();
+        writer
+            .write_i64_le(-42)
+            .and_then(|writer| writer.write_u32_be(0xDEAD_BEEF))
+            .and_then(|writer| writer.write_array(*b"ok"))
+            .unwrap();
+        let (frame, length) = writer.finish();
+
+        let mut reader = ArrayReader::new(&frame[..length]);
+        assert_eq!(reader.read_i64_le(), Ok(-42));
+        assert_eq!(reader.read_u32_be(), Ok(0xDEAD_BEEF));
+        assert_eq!(reader.read_array(), Ok(*b"ok"));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn const_contexts() {
+        const HEADER: [u8; 4] = [0x12, 0x34, 0x56, 0x78];
+        const VALUE: u32 = match ArrayReader::new(&HEADER).read_u32_be() {
+            Ok(value) => value,
+            Err(_) => 0,
+        };
+        assert_eq!(VALUE, 0x1234_5678);
+    }
+}
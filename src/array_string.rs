@@ -0,0 +1,472 @@
+//! A heapless string with a fixed capacity.
+//!
+//! See [`ArrayString`] for more information.
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+use core::str::{self, Utf8Error};
+
+use crate::SizeError;
+
+/// A UTF-8 string with a fixed `CAPACITY` in bytes, which stores its contents in a sized array.
+///
+/// It dereferences to [`str`](prim@str), so all string slice methods are available. Text is
+/// appended with [`push`](ArrayString::push), [`push_str`](ArrayString::push_str) or the
+/// [`write!`](::core::write) macro. Appending text which does not fit appends nothing and
+/// reports an error instead of panicking.
+///
+/// Fixed-width text fields, such as names stored in EEPROM, are converted from and to
+/// NUL-terminated and space-padded `[u8; CAPACITY]` buffers.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::ArrayString;
+/// use core::fmt::Write;
+///
+/// let eeprom = *b"sensor\0\0\0\0\0\0\0\0\0\0";
+/// let mut name: ArrayString<16> = ArrayString::from_nul_terminated(eeprom)?;
+/// assert_eq!(name, "sensor");
+///
+/// write!(name, "-{}", 42).unwrap();
+/// assert_eq!(name.to_space_padded(), *b"sensor-42       ");
+/// assert_eq!(&name[..6], "sensor");
+/// # Ok::<(), core::str::Utf8Error>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayString<const CAPACITY: usize> {
+    buffer: [u8; CAPACITY],
+    length: usize,
+}
+
+impl<const CAPACITY: usize> ArrayString<CAPACITY> {
+    /// Create a new empty string.
+    pub const fn new() -> Self {
+        ArrayString {
+            buffer: [0; CAPACITY],
+            length: 0,
+        }
+    }
+
+    /// The length of the string in bytes.
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Whether the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Whether the string has no capacity left.
+    pub const fn is_full(&self) -> bool {
+        self.length == CAPACITY
+    }
+
+    /// The maximum length of the string in bytes.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// The amount of bytes that can still be appended.
+    pub const fn remaining_capacity(&self) -> usize {
+        CAPACITY - self.length
+    }
+
+    /// Extract a string slice of the entire string.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The first `length` bytes of the buffer are always valid UTF-8.
+        unsafe { str::from_utf8_unchecked(&self.buffer[..self.length]) }
+    }
+
+    /// Extract a mutable string slice of the entire string.
+    pub fn as_mut_str(&mut self) -> &mut str {
+        // SAFETY: The first `length` bytes of the buffer are always valid UTF-8.
+        unsafe { str::from_utf8_unchecked_mut(&mut self.buffer[..self.length]) }
+    }
+
+    /// Append a character to the end of the string.
+    ///
+    /// Returns the character back if it does not fit.
+    pub fn push(&mut self, character: char) -> Result<(), char> {
+        self.push_str(character.encode_utf8(&mut [0; 4]))
+            .map_err(|_| character)
+    }
+
+    /// Append a string slice to the end of the string.
+    ///
+    /// If `string` does not fit, nothing is appended and a
+    /// [`SizeError::Truncated`](crate::SizeError::Truncated) with the amount of bytes that did
+    /// not fit is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::{ArrayString, SizeError};
+    ///
+    /// let mut string: ArrayString<8> = ArrayString::new();
+    /// assert_eq!(string.push_str("hello"), Ok(()));
+    /// assert_eq!(string.push_str(" world"), Err(SizeError::Truncated { dropped: 3 }));
+    /// assert_eq!(string, "hello");
+    /// ```
+    pub fn push_str(&mut self, string: &str) -> Result<(), SizeError> {
+        let remaining = self.remaining_capacity();
+        if string.len() > remaining {
+            return Err(SizeError::Truncated {
+                dropped: string.len() - remaining,
+            });
+        }
+
+        self.buffer[self.length..self.length + string.len()].copy_from_slice(string.as_bytes());
+        self.length += string.len();
+        Ok(())
+    }
+
+    /// Remove the last character of the string and return it.
+    pub fn pop(&mut self) -> Option<char> {
+        let character = self.as_str().chars().next_back()?;
+        self.length -= character.len_utf8();
+        Some(character)
+    }
+
+    /// Shorten the string to at most `new_length` bytes.
+    ///
+    /// If `new_length` does not lie on a character boundary, the string is shortened further to
+    /// the previous boundary, so no character is ever split. Does nothing if `new_length` is
+    /// greater than or equal to the length of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::ArrayString;
+    /// use core::convert::TryFrom;
+    ///
+    /// let mut string = ArrayString::<8>::try_from("añb").unwrap();
+    /// string.truncate(2);
+    /// assert_eq!(string, "a");
+    /// ```
+    pub fn truncate(&mut self, new_length: usize) {
+        if new_length >= self.length {
+            return;
+        }
+
+        let mut length = new_length;
+        while !self.as_str().is_char_boundary(length) {
+            length -= 1;
+        }
+        self.length = length;
+    }
+
+    /// Remove all characters from the string.
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+
+    /// Create a string from a buffer of UTF-8 bytes.
+    ///
+    /// All `CAPACITY` bytes are part of the string.
+    pub fn from_utf8(bytes: [u8; CAPACITY]) -> Result<Self, Utf8Error> {
+        str::from_utf8(&bytes)?;
+        Ok(ArrayString {
+            buffer: bytes,
+            length: CAPACITY,
+        })
+    }
+
+    /// Create a string from a NUL-terminated buffer of UTF-8 bytes.
+    ///
+    /// The string ends before the first NUL byte. If there is no NUL byte, all `CAPACITY` bytes are
+    /// part of the string, which is how fixed-width text fields are often stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::ArrayString;
+    ///
+    /// let name = ArrayString::from_nul_terminated(*b"abc\0xyz")?;
+    /// assert_eq!(name, "abc");
+    ///
+    /// let full = ArrayString::from_nul_terminated(*b"abcdefg")?;
+    /// assert_eq!(full, "abcdefg");
+    /// # Ok::<(), core::str::Utf8Error>(())
+    /// ```
+    pub fn from_nul_terminated(bytes: [u8; CAPACITY]) -> Result<Self, Utf8Error> {
+        let length = bytes.iter().position(|&byte| byte == 0).unwrap_or(CAPACITY);
+        Self::from_prefix(bytes, length)
+    }
+
+    /// Create a string from a space-padded buffer of UTF-8 bytes.
+    ///
+    /// All trailing spaces are removed from the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::ArrayString;
+    ///
+    /// let label = ArrayString::from_space_padded(*b"FAT32   ")?;
+    /// assert_eq!(label, "FAT32");
+    /// # Ok::<(), core::str::Utf8Error>(())
+    /// ```
+    pub fn from_space_padded(bytes: [u8; CAPACITY]) -> Result<Self, Utf8Error> {
+        let length = bytes
+            .iter()
+            .rposition(|&byte| byte != b' ')
+            .map_or(0, |index| index + 1);
+        Self::from_prefix(bytes, length)
+    }
+
+    /// Create a string from the first `length` bytes of a buffer.
+    fn from_prefix(mut bytes: [u8; CAPACITY], length: usize) -> Result<Self, Utf8Error> {
+        str::from_utf8(&bytes[..length])?;
+        bytes[length..].fill(0);
+        Ok(ArrayString {
+            buffer: bytes,
+            length,
+        })
+    }
+
+    /// Convert the string into a NUL-terminated buffer.
+    ///
+    /// All bytes after the string are NUL bytes.
+    ///
+    /// **The buffer is only NUL-terminated if the string is shorter than `CAPACITY`.** A string
+    /// which fills the entire capacity is returned without a terminator, matching
+    /// [`from_nul_terminated`](ArrayString::from_nul_terminated). Code which expects a terminator,
+    /// such as C functions, reads past the end of such a buffer. Check
+    /// [`is_full`](ArrayString::is_full) first, or reserve a byte by using a capacity one larger
+    /// than the longest string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::ArrayString;
+    /// use core::convert::TryFrom;
+    ///
+    /// let short: ArrayString<4> = ArrayString::try_from("abc")?;
+    /// assert_eq!(short.to_nul_terminated(), *b"abc\0");
+    ///
+    /// let full: ArrayString<4> = ArrayString::try_from("abcd")?;
+    /// assert!(full.is_full());
+    /// assert_eq!(full.to_nul_terminated(), *b"abcd");
+    /// # Ok::<(), array_utils::SizeError>(())
+    /// ```
+    pub fn to_nul_terminated(&self) -> [u8; CAPACITY] {
+        self.padded(0)
+    }
+
+    /// Convert the string into a space-padded buffer.
+    ///
+    /// All bytes after the string are spaces.
+    pub fn to_space_padded(&self) -> [u8; CAPACITY] {
+        self.padded(b' ')
+    }
+
+    /// Copy the bytes of the string into a buffer, filling the rest with `fill`.
+    fn padded(&self, fill: u8) -> [u8; CAPACITY] {
+        let mut bytes = [fill; CAPACITY];
+        bytes[..self.length].copy_from_slice(self.as_bytes());
+        bytes
+    }
+}
+
+impl<const CAPACITY: usize> Default for ArrayString<CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAPACITY: usize> Deref for ArrayString<CAPACITY> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAPACITY: usize> DerefMut for ArrayString<CAPACITY> {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const CAPACITY: usize> AsRef<str> for ArrayString<CAPACITY> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAPACITY: usize> Borrow<str> for ArrayString<CAPACITY> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAPACITY: usize> fmt::Write for ArrayString<CAPACITY> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push_str(string).map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, character: char) -> fmt::Result {
+        self.push(character).map_err(|_| fmt::Error)
+    }
+
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        // Formatted text is written in multiple pieces, so the pieces which did fit are removed
+        // again if a later one does not.
+        let length = self.length;
+        let result = fmt::write(self, args);
+        if result.is_err() {
+            self.length = length;
+        }
+        result
+    }
+}
+
+impl<const CAPACITY: usize> fmt::Display for ArrayString<CAPACITY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const CAPACITY: usize> fmt::Debug for ArrayString<CAPACITY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const CAPACITY: usize> TryFrom<&str> for ArrayString<CAPACITY> {
+    type Error = SizeError;
+
+    fn try_from(string: &str) -> Result<Self, SizeError> {
+        let mut array_string = Self::new();
+        array_string.push_str(string)?;
+        Ok(array_string)
+    }
+}
+
+impl<const CAPACITY: usize, const OTHER_CAPACITY: usize> PartialEq<ArrayString<OTHER_CAPACITY>>
+    for ArrayString<CAPACITY>
+{
+    fn eq(&self, other: &ArrayString<OTHER_CAPACITY>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const CAPACITY: usize> PartialEq<str> for ArrayString<CAPACITY> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const CAPACITY: usize> PartialEq<&str> for ArrayString<CAPACITY> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const CAPACITY: usize> Eq for ArrayString<CAPACITY> {}
+
+impl<const CAPACITY: usize> PartialOrd for ArrayString<CAPACITY> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const CAPACITY: usize> Ord for ArrayString<CAPACITY> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const CAPACITY: usize> Hash for ArrayString<CAPACITY> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn pushing() {
+        let mut string: ArrayString<6> = ArrayString::new();
+        assert_eq!(string.push('é'), Ok(()));
+        assert_eq!(string.push_str("abc"), Ok(()));
+        assert_eq!(string.push('€'), Err('€'));
+        assert_eq!(string.push('!'), Ok(()));
+        assert!(string.is_full());
+        assert_eq!(string, "éabc!");
+
+        assert_eq!(string.pop(), Some('!'));
+        assert_eq!(string.remaining_capacity(), 1);
+        string.clear();
+        assert_eq!(string.pop(), None);
+
+        let mut empty: ArrayString<0> = ArrayString::default();
+        assert_eq!(empty.push_str(""), Ok(()));
+        assert_eq!(empty.push('a'), Err('a'));
+    }
+
+    #[test]
+    fn truncating() {
+        let mut string = ArrayString::<16>::try_from("a€b").unwrap();
+        string.truncate(100);
+        assert_eq!(string, "a€b");
+        string.truncate(3);
+        assert_eq!(string, "a");
+        string.truncate(0);
+        assert!(string.is_empty());
+    }
+
+    #[test]
+    fn formatting() {
+        let mut string: ArrayString<8> = ArrayString::new();
+        assert!(write!(string, "{}-{}", 12, 34).is_ok());
+        assert_eq!(string, "12-34");
+        assert!(write!(string, "{}", 5678).is_err());
+        assert_eq!(string, "12-34");
+        assert!(write!(string, "{}{}", 5, 678).is_err());
+        assert_eq!(string, "12-34");
+        assert!(write!(string, "{}{}", 5, 67).is_ok());
+        assert_eq!(string, "12-34567");
+        assert_eq!(
+            ArrayString::<2>::try_from("abc"),
+            Err(SizeError::Truncated { dropped: 1 })
+        );
+    }
+
+    #[test]
+    fn buffers() {
+        assert!(ArrayString::from_utf8([0xFF, b'a']).is_err());
+        assert!(ArrayString::from_nul_terminated([b'a', 0, 0xFF]).is_ok());
+        assert!(ArrayString::from_nul_terminated([0xFF, 0, b'a']).is_err());
+        assert_eq!(ArrayString::from_utf8(*b"ab").unwrap(), "ab");
+        assert_eq!(ArrayString::from_space_padded(*b"    ").unwrap(), "");
+        assert_eq!(ArrayString::from_space_padded(*b" a b  ").unwrap(), " a b");
+
+        let string = ArrayString::from_nul_terminated(*b"ab\0cd").unwrap();
+        assert_eq!(string.to_nul_terminated(), *b"ab\0\0\0");
+        assert_eq!(string.to_space_padded(), *b"ab   ");
+        assert_eq!(
+            ArrayString::from_space_padded(string.to_space_padded()).unwrap(),
+            string
+        );
+    }
+
+    #[test]
+    fn comparing() {
+        let small = ArrayString::<4>::try_from("abc").unwrap();
+        let large = ArrayString::<32>::try_from("abc").unwrap();
+        assert_eq!(small, large);
+        assert!(small < ArrayString::try_from("abd").unwrap());
+        assert_eq!(small.len(), 3);
+        assert!(small.starts_with("ab"));
+    }
+}
@@ -0,0 +1,691 @@
+//! A heapless vector with a fixed capacity.
+//!
+//! See [`ArrayVec`] for more information.
+
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::slice;
+
+/// A vector with a fixed `CAPACITY`, which stores its elements in a sized array.
+///
+/// Where the utilities of this crate represent a partially filled array as a pair of an array and
+/// its logical length (e.g. [`initialize_till`](crate::initialize_till)), an `ArrayVec` keeps
+/// track of the logical length itself. Only the first [`len`](ArrayVec::len) elements are ever
+/// initialized, so no `fill` value is needed and `T` does not have to be
+/// [`Copy`](::core::marker::Copy).
+///
+/// Just like the rest of this crate, none of the operations panic. Pushing or inserting into a
+/// full vector hands the element back, and operations producing more elements than fit truncate
+/// them.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::ArrayVec;
+///
+/// let mut vec: ArrayVec<u8, 4> = ArrayVec::new();
+/// vec.push(1).unwrap();
+/// vec.extend([2, 3]);
+/// vec.insert(0, 0).unwrap();
+///
+/// assert_eq!(vec, [0, 1, 2, 3]);
+/// assert_eq!(vec.push(4), Err(4));
+/// assert_eq!(vec.remove(1), Some(1));
+/// assert_eq!(vec.iter().sum::<u8>(), 5);
+/// ```
+///
+/// The initializers returning an array together with its logical length convert directly into an
+/// `ArrayVec`.
+///
+/// ```
+/// use array_utils::{initialize_till, ArrayVec};
+///
+/// let vec: ArrayVec<usize, 8> = initialize_till(|index| index, 3, 0).into();
+/// assert_eq!(vec, [0, 1, 2]);
+/// ```
+pub struct ArrayVec<T, const CAPACITY: usize> {
+    buffer: [MaybeUninit<T>; CAPACITY],
+    length: usize,
+}
+
+impl<T, const CAPACITY: usize> ArrayVec<T, CAPACITY> {
+    /// Create a new empty vector.
+    pub const fn new() -> Self {
+        ArrayVec {
+            buffer: [const { MaybeUninit::uninit() }; CAPACITY],
+            length: 0,
+        }
+    }
+
+    /// The amount of elements in the vector.
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Whether the vector contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Whether the vector contains `CAPACITY` elements.
+    pub const fn is_full(&self) -> bool {
+        self.length == CAPACITY
+    }
+
+    /// The maximum amount of elements the vector can contain, which is `CAPACITY`.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// The amount of elements that can still be pushed onto the vector.
+    pub const fn remaining_capacity(&self) -> usize {
+        CAPACITY - self.length
+    }
+
+    /// Extract a slice containing all the elements of the vector.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `length` elements of the buffer are initialized.
+        unsafe { slice::from_raw_parts(self.buffer.as_ptr() as *const T, self.length) }
+    }
+
+    /// Extract a mutable slice containing all the elements of the vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The first `length` elements of the buffer are initialized.
+        unsafe { slice::from_raw_parts_mut(self.buffer.as_mut_ptr() as *mut T, self.length) }
+    }
+
+    /// Append an element to the back of the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `value` back if the vector is full.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        self.buffer[self.length].write(value);
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Remove the last element of the vector and return it, or [`None`] if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.length -= 1;
+        // SAFETY: The element at `length` was initialized and is no longer part of the vector.
+        Some(unsafe { self.buffer[self.length].assume_init_read() })
+    }
+
+    /// Insert an element at position `index`, shifting all elements after it to the right.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `value` back if the vector is full or `index` is greater than the length of
+    /// the vector.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), T> {
+        if self.is_full() || index > self.length {
+            return Err(value);
+        }
+
+        // SAFETY: There is room for one more element, so shifting the elements `index..length`
+        // one to the right stays within the buffer. Afterwards, the slot at `index` is
+        // overwritten without dropping its old value, which has been moved.
+        unsafe {
+            let slot = self.buffer.as_mut_ptr().add(index);
+            ptr::copy(slot, slot.add(1), self.length - index);
+            (*slot).write(value);
+        }
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Remove and return the element at position `index`, shifting all elements after it to the
+    /// left. Returns [`None`] if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        // SAFETY: The element at `index` is initialized. After it is read, the elements
+        // `index + 1..length` are shifted over it, which leaves the last slot unused.
+        unsafe {
+            let slot = self.buffer.as_mut_ptr().add(index);
+            let value = (*slot).assume_init_read();
+            ptr::copy(slot.add(1), slot, self.length - index - 1);
+            self.length -= 1;
+            Some(value)
+        }
+    }
+
+    /// Remove the element at position `index` by replacing it with the last element. Returns
+    /// [`None`] if `index` is out of bounds.
+    ///
+    /// This does not preserve the order of the elements, but does not need to shift them either.
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        let last = self.length - 1;
+        self.as_mut_slice().swap(index, last);
+        self.pop()
+    }
+
+    /// Shorten the vector to `length` elements, dropping the rest. Does nothing if the vector
+    /// contains `length` or less elements.
+    pub fn truncate(&mut self, length: usize) {
+        if length >= self.length {
+            return;
+        }
+
+        let tail = ptr::slice_from_raw_parts_mut(
+            self.buffer[length..].as_mut_ptr() as *mut T,
+            self.length - length,
+        );
+        // The length is set first, so that a panicking destructor cannot cause a double drop.
+        self.length = length;
+        // SAFETY: The elements `length..old_length` are initialized and no longer part of the
+        // vector.
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    /// Remove all elements from the vector.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Resize the vector to `length` elements, pushing clones of `value` if it is too short.
+    ///
+    /// A `length` greater than `CAPACITY` is treated as `CAPACITY`.
+    pub fn resize(&mut self, length: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.resize_with(length, || value.clone());
+    }
+
+    /// Resize the vector to `length` elements, pushing the results of `fill` if it is too short.
+    ///
+    /// A `length` greater than `CAPACITY` is treated as `CAPACITY`.
+    pub fn resize_with<F>(&mut self, length: usize, mut fill: F)
+    where
+        F: FnMut() -> T,
+    {
+        let length = if length < CAPACITY { length } else { CAPACITY };
+        self.truncate(length);
+        while self.length < length {
+            let _ = self.push(fill());
+        }
+    }
+
+    /// Create a vector of a different capacity, containing the same elements.
+    ///
+    /// The elements that don't fit in the new capacity are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::ArrayVec;
+    ///
+    /// let vec = ArrayVec::<u8, 4>::from([1, 2, 3, 4]);
+    /// assert_eq!(vec.clone().into_capacity::<8>(), [1, 2, 3, 4]);
+    /// assert_eq!(vec.into_capacity::<2>(), [1, 2]);
+    /// ```
+    pub fn into_capacity<const OUTPUT_CAPACITY: usize>(self) -> ArrayVec<T, OUTPUT_CAPACITY> {
+        self.into_iter().collect()
+    }
+
+    /// Join two vectors together into a new vector.
+    ///
+    /// Creates a vector containing all the elements of `self` followed by all the elements of
+    /// `right`. Unlike [`join`](crate::join), the result is never filled up. Any elements that
+    /// don't fit in `RESULT_CAPACITY` are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::ArrayVec;
+    ///
+    /// let left = ArrayVec::<u8, 4>::from(([1, 2, 0, 0], 2));
+    /// let right = ArrayVec::<u8, 4>::from(([3, 4, 5, 0], 3));
+    /// assert_eq!(left.join::<4, 8>(right), [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn join<const RIGHT_CAPACITY: usize, const RESULT_CAPACITY: usize>(
+        self,
+        right: ArrayVec<T, RIGHT_CAPACITY>,
+    ) -> ArrayVec<T, RESULT_CAPACITY> {
+        self.into_iter().chain(right).collect()
+    }
+
+    /// Splice a vector into two vectors.
+    ///
+    /// The left vector is filled up first, then the right. Unlike [`splice`](crate::splice), the
+    /// results are never filled up. Any elements that don't fit in both vectors are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::ArrayVec;
+    ///
+    /// let vec = ArrayVec::<u8, 8>::from(([1, 2, 3, 4, 5, 0, 0, 0], 5));
+    /// let (left, right) = vec.splice::<3, 3>();
+    /// assert_eq!(left, [1, 2, 3]);
+    /// assert_eq!(right, [4, 5]);
+    /// ```
+    pub fn splice<const LEFT_CAPACITY: usize, const RIGHT_CAPACITY: usize>(
+        self,
+    ) -> (ArrayVec<T, LEFT_CAPACITY>, ArrayVec<T, RIGHT_CAPACITY>) {
+        let mut elements = self.into_iter();
+        let left = elements.by_ref().collect();
+        let right = elements.collect();
+        (left, right)
+    }
+
+    /// Convert a full vector into a sized array.
+    ///
+    /// # Errors
+    ///
+    /// Returns the vector back if it is not full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::ArrayVec;
+    ///
+    /// let mut vec = ArrayVec::<u8, 3>::from(([1, 2, 0], 2));
+    /// vec = vec.into_inner().unwrap_err();
+    /// vec.push(3).unwrap();
+    /// assert_eq!(vec.into_inner(), Ok([1, 2, 3]));
+    /// ```
+    pub fn into_inner(self) -> Result<[T; CAPACITY], Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+
+        // SAFETY: The vector is full.
+        Ok(unsafe { self.into_inner_unchecked() })
+    }
+
+    /// Move the elements of the vector into a sized array without checking that it is full.
+    ///
+    /// # Safety
+    ///
+    /// The vector must be full.
+    pub(crate) unsafe fn into_inner_unchecked(self) -> [T; CAPACITY] {
+        let vec = ManuallyDrop::new(self);
+        // SAFETY: The vector is full, so all elements of the buffer are initialized. The vector is
+        // never used or dropped again, so the ownership of the elements moves to the array.
+        unsafe { ptr::read(vec.buffer.as_ptr() as *const [T; CAPACITY]) }
+    }
+
+    /// Create a sized array containing the elements of the vector, filling the remaining
+    /// elements with the `fill` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::ArrayVec;
+    ///
+    /// let vec = ArrayVec::<u8, 4>::from(([1, 2, 9, 9], 2));
+    /// assert_eq!(vec.to_array(0), [1, 2, 0, 0]);
+    /// ```
+    pub fn to_array(&self, fill: T) -> [T; CAPACITY]
+    where
+        T: Copy,
+    {
+        let mut buffer = [fill; CAPACITY];
+        buffer[..self.length].copy_from_slice(self);
+        buffer
+    }
+}
+
+impl<T, const CAPACITY: usize> Drop for ArrayVec<T, CAPACITY> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for ArrayVec<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const CAPACITY: usize> Clone for ArrayVec<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const CAPACITY: usize> fmt::Debug for ArrayVec<T, CAPACITY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const CAPACITY: usize> Deref for ArrayVec<T, CAPACITY> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const CAPACITY: usize> DerefMut for ArrayVec<T, CAPACITY> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize, const OTHER_CAPACITY: usize>
+    PartialEq<ArrayVec<T, OTHER_CAPACITY>> for ArrayVec<T, CAPACITY>
+{
+    fn eq(&self, other: &ArrayVec<T, OTHER_CAPACITY>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for ArrayVec<T, CAPACITY> {}
+
+impl<T: PartialEq, const CAPACITY: usize, const SIZE: usize> PartialEq<[T; SIZE]>
+    for ArrayVec<T, CAPACITY>
+{
+    fn eq(&self, other: &[T; SIZE]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize> PartialEq<[T]> for ArrayVec<T, CAPACITY> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, const CAPACITY: usize> From<[T; CAPACITY]> for ArrayVec<T, CAPACITY> {
+    /// Create a full vector from a sized array.
+    fn from(array: [T; CAPACITY]) -> Self {
+        IntoIterator::into_iter(array).collect()
+    }
+}
+
+impl<T, const CAPACITY: usize> From<([T; CAPACITY], usize)> for ArrayVec<T, CAPACITY> {
+    /// Create a vector from a sized array and its logical length, as returned by
+    /// [`initialize_till`](crate::initialize_till) and similar utilities.
+    ///
+    /// The elements of the array after the logical length are dropped.
+    fn from((array, length): ([T; CAPACITY], usize)) -> Self {
+        let mut vec = Self::from(array);
+        vec.truncate(length);
+        vec
+    }
+}
+
+impl<T, const CAPACITY: usize> Extend<T> for ArrayVec<T, CAPACITY> {
+    /// Push the elements of `iter` until the vector is full. Remaining elements are not taken
+    /// from the iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        while !self.is_full() {
+            match iter.next() {
+                Some(value) => {
+                    let _ = self.push(value);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> FromIterator<T> for ArrayVec<T, CAPACITY> {
+    /// Collect the elements of `iter` until the vector is full. Remaining elements are not taken
+    /// from the iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a ArrayVec<T, CAPACITY> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a mut ArrayVec<T, CAPACITY> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const CAPACITY: usize> IntoIterator for ArrayVec<T, CAPACITY> {
+    type Item = T;
+    type IntoIter = IntoIter<T, CAPACITY>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let vec = ManuallyDrop::new(self);
+        IntoIter {
+            // SAFETY: The vector is never used or dropped again, so the ownership of its
+            // initialized elements moves to the iterator.
+            buffer: unsafe { ptr::read(&vec.buffer) },
+            front: 0,
+            back: vec.length,
+        }
+    }
+}
+
+/// An iterator that moves the elements out of an [`ArrayVec`].
+///
+/// Created by the [`into_iter`](IntoIterator::into_iter) method of [`ArrayVec`].
+pub struct IntoIter<T, const CAPACITY: usize> {
+    buffer: [MaybeUninit<T>; CAPACITY],
+    front: usize,
+    back: usize,
+}
+
+impl<T, const CAPACITY: usize> IntoIter<T, CAPACITY> {
+    /// Extract a slice containing the remaining elements of the iterator.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The elements `front..back` of the buffer are initialized.
+        unsafe {
+            slice::from_raw_parts(
+                self.buffer.as_ptr().add(self.front) as *const T,
+                self.back - self.front,
+            )
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Iterator for IntoIter<T, CAPACITY> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        // SAFETY: The element at the old `front` is initialized and no longer part of the
+        // remaining elements.
+        Some(unsafe { self.buffer[self.front - 1].assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const CAPACITY: usize> DoubleEndedIterator for IntoIter<T, CAPACITY> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        // SAFETY: The element at `back` is initialized and no longer part of the remaining
+        // elements.
+        Some(unsafe { self.buffer[self.back].assume_init_read() })
+    }
+}
+
+impl<T, const CAPACITY: usize> ExactSizeIterator for IntoIter<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> FusedIterator for IntoIter<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> Drop for IntoIter<T, CAPACITY> {
+    fn drop(&mut self) {
+        let remaining = ptr::slice_from_raw_parts_mut(
+            self.buffer[self.front..].as_mut_ptr() as *mut T,
+            self.back - self.front,
+        );
+        self.front = self.back;
+        // SAFETY: The elements `front..back` are initialized and are never read again.
+        unsafe { ptr::drop_in_place(remaining) };
+    }
+}
+
+impl<T: fmt::Debug, const CAPACITY: usize> fmt::Debug for IntoIter<T, CAPACITY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Tracked;
+    use core::cell::Cell;
+
+    #[test]
+    fn push_pop() {
+        let mut vec: ArrayVec<usize, 3> = ArrayVec::new();
+        assert!(vec.is_empty());
+        assert_eq!(vec.pop(), None);
+        assert_eq!(vec.push(1), Ok(()));
+        assert_eq!(vec.push(2), Ok(()));
+        assert_eq!(vec.push(3), Ok(()));
+        assert_eq!(vec.push(4), Err(4));
+        assert!(vec.is_full());
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!(vec.pop(), Some(3));
+        assert_eq!(vec.remaining_capacity(), 1);
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut vec: ArrayVec<usize, 4> = ArrayVec::new();
+        assert_eq!(vec.insert(1, 0), Err(0));
+        assert_eq!(vec.insert(0, 1), Ok(()));
+        assert_eq!(vec.insert(0, 0), Ok(()));
+        assert_eq!(vec.insert(2, 3), Ok(()));
+        assert_eq!(vec.insert(2, 2), Ok(()));
+        assert_eq!(vec.insert(2, 9), Err(9));
+        assert_eq!(vec, [0, 1, 2, 3]);
+
+        assert_eq!(vec.remove(4), None);
+        assert_eq!(vec.remove(1), Some(1));
+        assert_eq!(vec.swap_remove(0), Some(0));
+        assert_eq!(vec, [3, 2]);
+    }
+
+    #[test]
+    fn extend_truncate() {
+        let mut vec: ArrayVec<usize, 4> = ArrayVec::new();
+        let mut iter = 0..10;
+        vec.extend(&mut iter);
+        assert_eq!(vec, [0, 1, 2, 3]);
+        assert_eq!(iter.next(), Some(4));
+
+        vec.truncate(6);
+        assert_eq!(vec.len(), 4);
+        vec.truncate(1);
+        assert_eq!(vec, [0]);
+
+        vec.resize(3, 7);
+        assert_eq!(vec, [0, 7, 7]);
+        vec.resize(9, 8);
+        assert_eq!(vec, [0, 7, 7, 8]);
+    }
+
+    #[test]
+    fn join_splice() {
+        let left = ArrayVec::<usize, 4>::from(([1, 2, 0, 0], 2));
+        let right = ArrayVec::<usize, 4>::from([3, 4, 5, 6]);
+        assert_eq!(left.clone().join::<4, 5>(right.clone()), [1, 2, 3, 4, 5]);
+        assert_eq!(left.join::<4, 8>(right), [1, 2, 3, 4, 5, 6]);
+
+        let vec = ArrayVec::<usize, 4>::from([1, 2, 3, 4]);
+        let (left, right) = vec.clone().splice::<1, 2>();
+        assert_eq!((left.as_slice(), right.as_slice()), (&[1][..], &[2, 3][..]));
+        let (left, right) = vec.splice::<3, 3>();
+        assert_eq!(
+            (left.as_slice(), right.as_slice()),
+            (&[1, 2, 3][..], &[4][..])
+        );
+    }
+
+    #[test]
+    fn into_inner() {
+        let vec = ArrayVec::<usize, 3>::from(([1, 2, 3], 2));
+        let mut vec = vec.into_inner().unwrap_err();
+        assert_eq!(vec, [1, 2]);
+        assert_eq!(vec.push(4), Ok(()));
+        assert_eq!(vec.into_inner(), Ok([1, 2, 4]));
+        assert_eq!(ArrayVec::<usize, 0>::new().into_inner(), Ok([]));
+    }
+
+    #[test]
+    fn into_iter() {
+        let vec = ArrayVec::<usize, 5>::from(([1, 2, 3, 4, 0], 4));
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.as_slice(), [2, 3]);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<ArrayVec<usize, 2>>(), [2, 3]);
+    }
+
+    #[test]
+    fn drops() {
+        let drops = Cell::new(0);
+
+        let mut vec: ArrayVec<Tracked, 4> = (0..4).map(|index| Tracked(index, &drops)).collect();
+        assert!(vec.push(Tracked(4, &drops)).is_err());
+        assert_eq!(drops.get(), 1);
+
+        vec.truncate(2);
+        assert_eq!(drops.get(), 3);
+        drop(vec.remove(0));
+        assert_eq!(drops.get(), 4);
+        assert_eq!(vec[0].0, 1);
+
+        vec.extend((0..2).map(|index| Tracked(index, &drops)));
+        let mut iter = vec.into_iter();
+        drop(iter.next());
+        assert_eq!(drops.get(), 5);
+        drop(iter);
+        assert_eq!(drops.get(), 7);
+
+        let vec = ArrayVec::<Tracked, 3>::from((
+            [Tracked(0, &drops), Tracked(1, &drops), Tracked(2, &drops)],
+            1,
+        ));
+        assert_eq!(drops.get(), 9);
+        drop(vec);
+        assert_eq!(drops.get(), 10);
+    }
+}
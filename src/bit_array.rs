@@ -0,0 +1,353 @@
+//! A fixed-size set of bits stored in an array of words.
+//!
+//! See [`BitArray`] for more information.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+/// The amount of bits in a single word of a [`BitArray`].
+const WORD_BITS: usize = u32::BITS as usize;
+
+/// A set of `WORDS * 32` bits, stored in a sized `[u32; WORDS]` array.
+///
+/// Bit `index` is stored in word `index / 32` at bit `index % 32`, counting from the least
+/// significant bit. Just like the rest of the crate, none of the operations panic. Setting,
+/// clearing or toggling a bit outside of the array does nothing, and testing it returns `false`.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::BitArray;
+///
+/// let mut flags: BitArray<4> = BitArray::new();
+/// flags.set(3);
+/// flags.set(100);
+/// flags.toggle(3);
+/// flags.set(42);
+///
+/// assert!(flags.test(42));
+/// assert_eq!(flags.count_ones(), 2);
+/// assert_eq!(flags.first_set(), Some(42));
+/// assert!(flags.iter_ones().eq([42, 100]));
+///
+/// let mask: BitArray<4> = BitArray::from_bools([true; 64]);
+/// assert!((flags & mask).iter_ones().eq([42]));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitArray<const WORDS: usize> {
+    words: [u32; WORDS],
+}
+
+impl<const WORDS: usize> BitArray<WORDS> {
+    /// The amount of bits in the array.
+    pub const BITS: usize = WORDS * WORD_BITS;
+
+    /// Create a new bit array with all bits cleared.
+    pub const fn new() -> Self {
+        BitArray { words: [0; WORDS] }
+    }
+
+    /// Create a bit array from its words.
+    pub const fn from_words(words: [u32; WORDS]) -> Self {
+        BitArray { words }
+    }
+
+    /// The words storing the bits.
+    pub const fn as_words(&self) -> &[u32; WORDS] {
+        &self.words
+    }
+
+    /// Consume the bit array, returning the words storing the bits.
+    pub const fn into_words(self) -> [u32; WORDS] {
+        self.words
+    }
+
+    /// Whether bit `index` is set. Returns `false` if `index` is out of range.
+    pub const fn test(&self, index: usize) -> bool {
+        index < Self::BITS && self.words[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+    }
+
+    /// Set bit `index`. Does nothing if `index` is out of range.
+    pub const fn set(&mut self, index: usize) {
+        if index < Self::BITS {
+            self.words[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+        }
+    }
+
+    /// Clear bit `index`. Does nothing if `index` is out of range.
+    pub const fn clear(&mut self, index: usize) {
+        if index < Self::BITS {
+            self.words[index / WORD_BITS] &= !(1 << (index % WORD_BITS));
+        }
+    }
+
+    /// Flip bit `index`. Does nothing if `index` is out of range.
+    pub const fn toggle(&mut self, index: usize) {
+        if index < Self::BITS {
+            self.words[index / WORD_BITS] ^= 1 << (index % WORD_BITS);
+        }
+    }
+
+    /// The amount of set bits.
+    pub const fn count_ones(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < WORDS {
+            count += self.words[i].count_ones() as usize;
+            i += 1;
+        }
+        count
+    }
+
+    /// Whether no bits are set.
+    pub const fn is_empty(&self) -> bool {
+        self.first_set().is_none()
+    }
+
+    /// The index of the lowest set bit, or [`None`] if no bits are set.
+    pub const fn first_set(&self) -> Option<usize> {
+        let mut i = 0;
+        while i < WORDS {
+            if self.words[i] != 0 {
+                return Some(i * WORD_BITS + self.words[i].trailing_zeros() as usize);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Iterate over the indices of the set bits, in ascending order.
+    pub fn iter_ones(&self) -> Ones<'_> {
+        Ones {
+            words: &self.words,
+            offset: 0,
+            current: 0,
+        }
+    }
+
+    /// Create a bit array from a sized array of booleans.
+    ///
+    /// Bit `i` is set if `bools[i]` is `true`. Booleans beyond the end of the bit array are
+    /// truncated.
+    pub const fn from_bools<const SIZE: usize>(bools: [bool; SIZE]) -> Self {
+        let mut bits = Self::new();
+        let mut i = 0;
+        while i < SIZE {
+            if bools[i] {
+                bits.set(i);
+            }
+            i += 1;
+        }
+        bits
+    }
+
+    /// Convert the bit array into a sized array of booleans.
+    ///
+    /// Element `i` is `true` if bit `i` is set. Elements beyond the end of the bit array are
+    /// `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::BitArray;
+    ///
+    /// let bits: BitArray<1> = BitArray::from_words([0b1010]);
+    /// assert_eq!(bits.to_bools(), [false, true, false, true, false]);
+    /// ```
+    pub const fn to_bools<const SIZE: usize>(&self) -> [bool; SIZE] {
+        let mut bools = [false; SIZE];
+        let mut i = 0;
+        while i < SIZE {
+            bools[i] = self.test(i);
+            i += 1;
+        }
+        bools
+    }
+}
+
+impl<const WORDS: usize> Default for BitArray<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize> fmt::Debug for BitArray<WORDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter_ones()).finish()
+    }
+}
+
+impl<const WORDS: usize> From<[u32; WORDS]> for BitArray<WORDS> {
+    fn from(words: [u32; WORDS]) -> Self {
+        Self::from_words(words)
+    }
+}
+
+macro_rules! bitwise_operators {
+    ($($operator:ident, $method:ident, $assign:ident, $assign_method:ident, $symbol:tt;)*) => {$(
+        impl<const WORDS: usize> $operator for BitArray<WORDS> {
+            type Output = Self;
+
+            fn $method(mut self, other: Self) -> Self {
+                self.$assign_method(other);
+                self
+            }
+        }
+
+        impl<const WORDS: usize> $assign for BitArray<WORDS> {
+            fn $assign_method(&mut self, other: Self) {
+                for (word, other) in self.words.iter_mut().zip(other.words) {
+                    *word $symbol other;
+                }
+            }
+        }
+    )*};
+}
+
+bitwise_operators! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, &=;
+    BitOr, bitor, BitOrAssign, bitor_assign, |=;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, ^=;
+}
+
+impl<const WORDS: usize> Not for BitArray<WORDS> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        BitArray {
+            words: self.words.map(|word| !word),
+        }
+    }
+}
+
+/// An iterator over the indices of the set bits of a [`BitArray`].
+///
+/// Created by [`BitArray::iter_ones`].
+#[derive(Debug, Clone)]
+pub struct Ones<'a> {
+    /// The words that have not been loaded yet
+    words: &'a [u32],
+    /// The index of the first bit after the loaded word
+    offset: usize,
+    /// The remaining set bits of the loaded word
+    current: u32,
+}
+
+impl Iterator for Ones<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            let (&word, rest) = self.words.split_first()?;
+            self.current = word;
+            self.words = rest;
+            self.offset += WORD_BITS;
+        }
+
+        // Clear the lowest set bit and report its index.
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some(self.offset - WORD_BITS + bit)
+    }
+}
+
+impl FusedIterator for Ones<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_bits() {
+        let mut bits: BitArray<2> = BitArray::new();
+        assert_eq!(BitArray::<2>::BITS, 64);
+        assert!(bits.is_empty());
+
+        bits.set(0);
+        bits.set(31);
+        bits.set(32);
+        bits.set(63);
+        assert_eq!(bits.as_words(), &[0x8000_0001, 0x8000_0001]);
+
+        bits.clear(31);
+        bits.toggle(32);
+        bits.toggle(33);
+        assert_eq!(bits.into_words(), [0x0000_0001, 0x8000_0002]);
+        assert!(bits.test(33));
+        assert!(!bits.test(32));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut bits: BitArray<1> = BitArray::new();
+        bits.set(32);
+        bits.toggle(usize::MAX);
+        bits.clear(100);
+        assert!(bits.is_empty());
+        assert!(!bits.test(usize::MAX));
+
+        let mut empty: BitArray<0> = BitArray::default();
+        empty.set(0);
+        assert_eq!(empty.first_set(), None);
+        assert_eq!(empty.iter_ones().next(), None);
+        assert_eq!(empty.to_bools(), [false; 3]);
+    }
+
+    #[test]
+    fn counting() {
+        let bits = BitArray::from_words([0, 0b1100, 0, 1 << 31]);
+        assert_eq!(bits.count_ones(), 3);
+        assert_eq!(bits.first_set(), Some(34));
+        assert!(bits.iter_ones().eq([34, 35, 127]));
+        assert_eq!(BitArray::<3>::new().first_set(), None);
+        assert_eq!(BitArray::from([u32::MAX; 2]).iter_ones().count(), 64);
+    }
+
+    #[test]
+    fn bitwise() {
+        let left = BitArray::from_words([0b1100, 1]);
+        let right = BitArray::from_words([0b1010, 0]);
+
+        assert_eq!((left & right).into_words(), [0b1000, 0]);
+        assert_eq!((left | right).into_words(), [0b1110, 1]);
+        assert_eq!((left ^ right).into_words(), [0b0110, 1]);
+        assert_eq!((!right).into_words(), [!0b1010, u32::MAX]);
+
+        let mut assigned = left;
+        assigned ^= left;
+        assert!(assigned.is_empty());
+    }
+
+    #[test]
+    fn bools() {
+        let bools = [true, false, false, true, true];
+        let bits: BitArray<1> = BitArray::from_bools(bools);
+        assert_eq!(bits.into_words(), [0b11001]);
+        assert_eq!(bits.to_bools(), bools);
+        assert_eq!(bits.to_bools(), [true, false]);
+
+        let truncated: BitArray<1> = BitArray::from_bools([true; 40]);
+        assert_eq!(truncated.count_ones(), 32);
+    }
+
+    #[test]
+    fn debug() {
+        extern crate std;
+        use std::format;
+
+        let bits: BitArray<2> = BitArray::from_bools([false, true, false, true]);
+        assert_eq!(format!("{:?}", bits), "{1, 3}");
+    }
+
+    #[test]
+    fn const_contexts() {
+        const MASK: BitArray<1> = {
+            let mut mask = BitArray::new();
+            mask.set(4);
+            mask.toggle(5);
+            mask
+        };
+        assert_eq!(MASK.into_words(), [0b110000]);
+    }
+}
@@ -0,0 +1,267 @@
+//! A cursor for serializing data into a fixed byte array.
+//!
+//! See [`ByteWriter`] for more information.
+
+use crate::SizeError;
+
+/// A cursor which appends bytes to a sized `[u8; SIZE]` buffer.
+///
+/// Integers are written in big or little endian, along with sized arrays and slices. The writer
+/// tracks how many bytes have been written. A write which does not fit in the remaining space
+/// writes nothing and returns a [`SizeError::Truncated`](crate::SizeError::Truncated) with the
+/// amount of bytes that did not fit. Once done, [`finish`](ByteWriter::finish) returns the buffer
+/// along with the written length, ready to be passed to [`sized_slice`](crate::sized_slice) or
+/// [`superimpose`](crate::superimpose).
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::{ByteWriter, SizeError};
+///
+/// let payload = [0xCA, 0xFE];
+///
+/// let mut writer: ByteWriter<8> = ByteWriter::new();
+/// writer
+///     .write_u8(0x7E)?
+///     .write_u16_be(payload.len() as u16)?
+///     .write_array(payload)?;
+///
+/// assert_eq!(writer.position(), 5);
+/// assert_eq!(writer.write_u32_le(0), Err(SizeError::Truncated { dropped: 1 }));
+///
+/// assert_eq!(writer.finish(), ([0x7E, 0x00, 0x02, 0xCA, 0xFE, 0, 0, 0], 5));
+/// # Ok::<(), SizeError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteWriter<const SIZE: usize> {
+    buffer: [u8; SIZE],
+    position: usize,
+}
+
+macro_rules! integer_writers {
+    ($($integer:ty: $big_endian:ident, $little_endian:ident;)*) => {$(
+        #[doc = concat!("Append a [`", stringify!($integer), "`] in big endian byte order.")]
+        pub const fn $big_endian(&mut self, value: $integer) -> Result<&mut Self, SizeError> {
+            self.write_slice(&value.to_be_bytes())
+        }
+
+        #[doc = concat!("Append a [`", stringify!($integer), "`] in little endian byte order.")]
+        pub const fn $little_endian(&mut self, value: $integer) -> Result<&mut Self, SizeError> {
+            self.write_slice(&value.to_le_bytes())
+        }
+    )*};
+}
+
+impl<const SIZE: usize> ByteWriter<SIZE> {
+    /// Create a new writer over a buffer of zeros.
+    pub const fn new() -> Self {
+        Self::filled(0)
+    }
+
+    /// Create a new writer over a buffer filled with the `fill` byte.
+    ///
+    /// The bytes which are never written keep the `fill` value.
+    pub const fn filled(fill: u8) -> Self {
+        ByteWriter {
+            buffer: [fill; SIZE],
+            position: 0,
+        }
+    }
+
+    /// The amount of bytes written so far, which is the index the next byte is written at.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The amount of bytes that can still be written.
+    pub const fn remaining(&self) -> usize {
+        SIZE - self.position
+    }
+
+    /// Whether no more bytes can be written.
+    pub const fn is_full(&self) -> bool {
+        self.position == SIZE
+    }
+
+    /// The bytes written so far.
+    pub const fn written(&self) -> &[u8] {
+        self.buffer.split_at(self.position).0
+    }
+
+    /// Append a slice of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::{ByteWriter, SizeError};
+    ///
+    /// let mut writer: ByteWriter<4> = ByteWriter::new();
+    /// assert!(writer.write_slice(b"abc").is_ok());
+    /// assert_eq!(writer.write_slice(b"de"), Err(SizeError::Truncated { dropped: 1 }));
+    /// assert_eq!(writer.written(), b"abc");
+    /// ```
+    pub const fn write_slice(&mut self, bytes: &[u8]) -> Result<&mut Self, SizeError> {
+        let remaining = self.remaining();
+        if bytes.len() > remaining {
+            return Err(SizeError::Truncated {
+                dropped: bytes.len() - remaining,
+            });
+        }
+
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buffer[self.position + i] = bytes[i];
+            i += 1;
+        }
+        self.position += bytes.len();
+        Ok(self)
+    }
+
+    /// Append a sized array of bytes.
+    pub const fn write_array<const LENGTH: usize>(
+        &mut self,
+        bytes: [u8; LENGTH],
+    ) -> Result<&mut Self, SizeError> {
+        self.write_slice(&bytes)
+    }
+
+    /// Skip over `count` bytes, leaving them as they are.
+    ///
+    /// This reserves space for a field which is filled in later, such as a length or a checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::{superimpose, ByteWriter};
+    ///
+    /// let mut writer: ByteWriter<5> = ByteWriter::new();
+    /// writer.skip(1)?.write_slice(b"data")?;
+    ///
+    /// let (frame, length) = writer.finish();
+    /// assert_eq!(superimpose(frame, [length as u8 - 1], 0), *b"\x04data");
+    /// # Ok::<(), array_utils::SizeError>(())
+    /// ```
+    pub const fn skip(&mut self, count: usize) -> Result<&mut Self, SizeError> {
+        let remaining = self.remaining();
+        if count > remaining {
+            return Err(SizeError::Truncated {
+                dropped: count - remaining,
+            });
+        }
+
+        self.position += count;
+        Ok(self)
+    }
+
+    /// Append a [`u8`].
+    pub const fn write_u8(&mut self, value: u8) -> Result<&mut Self, SizeError> {
+        self.write_slice(&[value])
+    }
+
+    /// Append a [`i8`].
+    pub const fn write_i8(&mut self, value: i8) -> Result<&mut Self, SizeError> {
+        self.write_slice(&value.to_be_bytes())
+    }
+
+    integer_writers! {
+        u16: write_u16_be, write_u16_le;
+        u32: write_u32_be, write_u32_le;
+        u64: write_u64_be, write_u64_le;
+        u128: write_u128_be, write_u128_le;
+        i16: write_i16_be, write_i16_le;
+        i32: write_i32_be, write_i32_le;
+        i64: write_i64_be, write_i64_le;
+        i128: write_i128_be, write_i128_le;
+    }
+
+    /// Consume the writer, returning the buffer and the amount of bytes written.
+    pub const fn finish(self) -> ([u8; SIZE], usize) {
+        (self.buffer, self.position)
+    }
+}
+
+impl<const SIZE: usize> Default for ByteWriter<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        let mut writer: ByteWriter<32> = ByteWriter::new();
+        writer
+            .write_u16_be(0x0102)
+            .and_then(|writer| writer.write_u16_le(0x0102))
+            .and_then(|writer| writer.write_i32_be(-2))
+            .and_then(|writer| writer.write_u64_le(0x0807_0605_0403_0201))
+            .and_then(|writer| writer.write_i8(-1))
+            .unwrap();
+
+        assert_eq!(
+            writer.written(),
+            [0x01, 0x02, 0x02, 0x01, 0xFF, 0xFF, 0xFF, 0xFE, 1, 2, 3, 4, 5, 6, 7, 8, 0xFF]
+        );
+        assert_eq!(writer.remaining(), 15);
+        assert_eq!(
+            writer.write_u128_be(0),
+            Err(SizeError::Truncated { dropped: 1 })
+        );
+        assert_eq!(writer.position(), 17);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut writer: ByteWriter<3> = ByteWriter::filled(0xAA);
+        assert_eq!(
+            writer.write_u32_be(1),
+            Err(SizeError::Truncated { dropped: 1 })
+        );
+        assert_eq!(
+            writer.skip(usize::MAX),
+            Err(SizeError::Truncated {
+                dropped: usize::MAX - 3
+            })
+        );
+        assert_eq!(writer.position(), 0);
+
+        assert!(writer.write_array([1, 2, 3]).is_ok());
+        assert!(writer.is_full());
+        assert!(writer.write_slice(&[]).is_ok());
+        assert_eq!(writer.write_u8(4), Err(SizeError::Truncated { dropped: 1 }));
+        assert_eq!(writer.finish(), ([1, 2, 3], 3));
+
+        let mut empty: ByteWriter<0> = ByteWriter::default();
+        assert!(empty.is_full());
+        assert_eq!(empty.write_u8(0), Err(SizeError::Truncated { dropped: 1 }));
+    }
+
+    #[test]
+    fn skipping() {
+        let mut writer: ByteWriter<4> = ByteWriter::filled(0xFF);
+        writer
+            .write_u8(1)
+            .and_then(|writer| writer.skip(2))
+            .and_then(|writer| writer.write_u8(4))
+            .unwrap();
+        assert_eq!(writer.finish(), ([1, 0xFF, 0xFF, 4], 4));
+    }
+
+    #[test]
+    fn const_contexts() {
+        const FRAME: ([u8; 6], usize) = {
+            let mut writer = ByteWriter::new();
+            match writer.write_u16_be(0xBEEF) {
+                Ok(writer) => {
+                    let _ = writer.write_u8(7);
+                }
+                Err(_) => panic!(),
+            }
+            writer.finish()
+        };
+        assert_eq!(FRAME, ([0xBE, 0xEF, 7, 0, 0, 0], 3));
+    }
+}
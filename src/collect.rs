@@ -0,0 +1,250 @@
+//! Collecting iterators into sized arrays.
+
+use core::fmt;
+
+use crate::ArrayVec;
+
+/// Error returned by [`try_collect_array`](crate::try_collect_array) when the iterator does not
+/// yield exactly the size of the array in elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectError {
+    /// The iterator ran out after yielding `count` elements.
+    TooFew {
+        /// Amount of elements the iterator yielded
+        count: usize,
+    },
+    /// The iterator yielded more elements than fit in the array.
+    TooMany,
+}
+
+impl fmt::Display for CollectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectError::TooFew { count } => {
+                write!(f, "iterator ran out after yielding {} elements", count)
+            }
+            CollectError::TooMany => write!(f, "iterator yielded more elements than fit"),
+        }
+    }
+}
+
+/// Collect the elements of an iterator into a sized array.
+///
+/// Takes at most `OUTPUT_SIZE` elements from `iter`. If the iterator runs out before that, the
+/// rest of the output array is filled with the `fill` value. Along with the collected array, this
+/// utility returns the amount of elements taken from the iterator.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::collect_array;
+///
+/// let bytes = b"AT+OK\r\n".iter().copied().take_while(|byte| *byte != b'\r');
+/// assert_eq!(collect_array(bytes, 0), ([b'A', b'T', b'+', b'O', b'K', 0, 0, 0], 5));
+///
+/// // Elements after the first `OUTPUT_SIZE` elements are not taken from the iterator.
+/// let mut numbers = 1..;
+/// assert_eq!(collect_array(&mut numbers, 0), ([1, 2, 3], 3));
+/// assert_eq!(numbers.next(), Some(4));
+/// ```
+pub fn collect_array<T, I, const OUTPUT_SIZE: usize>(iter: I, fill: T) -> ([T; OUTPUT_SIZE], usize)
+where
+    T: Copy,
+    I: IntoIterator<Item = T>,
+{
+    let mut buffer = [fill; OUTPUT_SIZE];
+    let mut length = 0;
+    for (slot, value) in buffer.iter_mut().zip(iter) {
+        *slot = value;
+        length += 1;
+    }
+    (buffer, length)
+}
+
+/// Collect the elements of an iterator into a sized array, without requiring
+/// [`Copy`](::core::marker::Copy).
+///
+/// Works the same as [`collect_array`](crate::collect_array), but the elements after the
+/// iterator runs out are produced by calling the `fill` closure once per element.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::collect_array_with;
+/// use core::cell::RefCell;
+///
+/// let (cells, length): ([RefCell<u8>; 4], usize) =
+///     collect_array_with((1..=3).map(RefCell::new), Default::default);
+/// assert_eq!(length, 3);
+/// assert_eq!(cells.map(RefCell::into_inner), [1, 2, 3, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the iterator or the given `fill` panics. All elements that were already
+/// collected are dropped in that case.
+pub fn collect_array_with<T, I, F, const OUTPUT_SIZE: usize>(
+    iter: I,
+    mut fill: F,
+) -> ([T; OUTPUT_SIZE], usize)
+where
+    I: IntoIterator<Item = T>,
+    F: FnMut() -> T,
+{
+    let mut iter = iter.into_iter().fuse();
+    let mut length = 0;
+    let buffer = ::core::array::from_fn(|_| match iter.next() {
+        Some(value) => {
+            length += 1;
+            value
+        }
+        None => fill(),
+    });
+    (buffer, length)
+}
+
+/// Collect exactly `OUTPUT_SIZE` elements of an iterator into a sized array.
+///
+/// The strict counterpart of [`collect_array`](crate::collect_array), which does not need a
+/// `fill` value. To check that the iterator does not yield too many elements, one element more
+/// than `OUTPUT_SIZE` is taken from the iterator.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::{try_collect_array, CollectError};
+///
+/// assert_eq!(try_collect_array("1 2 3".split(' ')), Ok(["1", "2", "3"]));
+/// assert_eq!(
+///     try_collect_array::<_, _, 4>("1 2 3".split(' ')),
+///     Err(CollectError::TooFew { count: 3 })
+/// );
+/// assert_eq!(
+///     try_collect_array::<_, _, 2>("1 2 3".split(' ')),
+///     Err(CollectError::TooMany)
+/// );
+/// ```
+///
+/// # Errors
+///
+/// * [`CollectError::TooFew`](crate::CollectError::TooFew) if the iterator yields less than
+///   `OUTPUT_SIZE` elements.
+/// * [`CollectError::TooMany`](crate::CollectError::TooMany) if the iterator yields more than
+///   `OUTPUT_SIZE` elements.
+///
+/// All elements that were collected are dropped in case of an error.
+pub fn try_collect_array<T, I, const OUTPUT_SIZE: usize>(
+    iter: I,
+) -> Result<[T; OUTPUT_SIZE], CollectError>
+where
+    I: IntoIterator<Item = T>,
+{
+    let mut iter = iter.into_iter();
+    let vec: ArrayVec<T, OUTPUT_SIZE> = iter.by_ref().collect();
+    if iter.next().is_some() {
+        return Err(CollectError::TooMany);
+    }
+
+    vec.into_inner()
+        .map_err(|vec| CollectError::TooFew { count: vec.len() })
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl<I: Iterator> Sealed for I {}
+}
+
+/// Collecting iterators into sized arrays as methods on iterators.
+///
+/// Every method forwards to the free function with the same name. This trait is sealed and
+/// cannot be implemented outside of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::IteratorExt;
+///
+/// let (squares, _) = (1..).map(|n: u32| n * n).collect_array(0);
+/// assert_eq!(squares, [1, 4, 9, 16]);
+///
+/// let fields = "12,34,56".split(',').try_collect_array::<3>();
+/// assert_eq!(fields, Ok(["12", "34", "56"]));
+/// ```
+pub trait IteratorExt: Iterator + private::Sealed + Sized {
+    /// Method form of [`collect_array`](crate::collect_array).
+    fn collect_array<const OUTPUT_SIZE: usize>(
+        self,
+        fill: Self::Item,
+    ) -> ([Self::Item; OUTPUT_SIZE], usize)
+    where
+        Self::Item: Copy,
+    {
+        collect_array(self, fill)
+    }
+
+    /// Method form of [`collect_array_with`](crate::collect_array_with).
+    fn collect_array_with<F, const OUTPUT_SIZE: usize>(
+        self,
+        fill: F,
+    ) -> ([Self::Item; OUTPUT_SIZE], usize)
+    where
+        F: FnMut() -> Self::Item,
+    {
+        collect_array_with(self, fill)
+    }
+
+    /// Method form of [`try_collect_array`](crate::try_collect_array).
+    fn try_collect_array<const OUTPUT_SIZE: usize>(
+        self,
+    ) -> Result<[Self::Item; OUTPUT_SIZE], CollectError> {
+        try_collect_array(self)
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect() {
+        assert_eq!(collect_array(0..3, 42), ([0, 1, 2, 42, 42], 3));
+        assert_eq!(collect_array(0..5, 42), ([0, 1, 2, 3, 4], 5));
+        assert_eq!(collect_array(0..9, 42), ([0, 1, 2], 3));
+        assert_eq!(collect_array(0..9, 42), ([], 0));
+    }
+
+    #[test]
+    fn collect_with() {
+        let mut calls = 0;
+        let (array, length): ([usize; 4], usize) = collect_array_with(0..2, || {
+            calls += 1;
+            42
+        });
+        assert_eq!((array, length, calls), ([0, 1, 42, 42], 2, 2));
+    }
+
+    #[test]
+    fn try_collect() {
+        assert_eq!(try_collect_array(0..3), Ok([0, 1, 2]));
+        assert_eq!(try_collect_array::<_, _, 0>(0..0), Ok([]));
+        assert_eq!(
+            try_collect_array::<_, _, 4>(0..3),
+            Err(CollectError::TooFew { count: 3 })
+        );
+        assert_eq!(
+            try_collect_array::<_, _, 2>(0..3),
+            Err(CollectError::TooMany)
+        );
+    }
+
+    #[test]
+    fn methods() {
+        let mut numbers = 0..;
+        assert_eq!(numbers.by_ref().collect_array(0), ([0, 1], 2));
+        assert_eq!(numbers.by_ref().collect_array_with(|| 0), ([2, 3, 4], 3));
+        assert_eq!(numbers.take(2).try_collect_array(), Ok([5, 6]));
+    }
+}
@@ -0,0 +1,412 @@
+//! Extension trait exposing the array utilities as methods on sized arrays.
+
+#[cfg(feature = "resize")]
+use crate::{array_resize, array_resize_into, array_resize_with};
+#[cfg(feature = "drift")]
+use crate::{
+    drift_to_begin, drift_to_begin_in_place, drift_to_begin_with, drift_to_end,
+    drift_to_end_in_place, drift_to_end_with,
+};
+#[cfg(feature = "join")]
+use crate::{join, join_with};
+#[cfg(feature = "slice")]
+use crate::{sized_slice, sized_slice_with};
+#[cfg(feature = "splice")]
+use crate::{splice, splice_with};
+#[cfg(feature = "superimpose")]
+use crate::{superimpose, superimpose_in_place, superimpose_move};
+
+mod private {
+    pub trait Sealed {}
+
+    impl<T, const SIZE: usize> Sealed for [T; SIZE] {}
+}
+
+/// The array utilities as methods on sized arrays.
+///
+/// Every method forwards to the free function with the same name and is enabled by the same
+/// _feature_. This allows for operations to be chained instead of nested.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::ArrayExt;
+///
+/// let frame: [u8; 8] = [0xAA, 0xBB]
+///     .join::<3, 6>([1, 2, 3], 0)
+///     .drift_to_begin(0, 1, 0)
+///     .resize(0xFF);
+/// assert_eq!(frame, [0, 0xAA, 0xBB, 1, 2, 3, 0xFF, 0xFF]);
+/// ```
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait ArrayExt<T, const SIZE: usize>: private::Sealed + Sized {
+    /// Method form of [`array_resize`](crate::array_resize).
+    #[cfg(feature = "resize")]
+    fn resize<const OUTPUT_SIZE: usize>(self, fill: T) -> [T; OUTPUT_SIZE]
+    where
+        T: Copy;
+
+    /// Method form of [`array_resize_with`](crate::array_resize_with).
+    #[cfg(feature = "resize")]
+    fn resize_with<F, const OUTPUT_SIZE: usize>(self, fill: F) -> [T; OUTPUT_SIZE]
+    where
+        F: FnMut() -> T;
+
+    /// Method form of [`array_resize_into`](crate::array_resize_into).
+    #[cfg(feature = "resize")]
+    fn resize_into<const OUTPUT_SIZE: usize>(&self, output: &mut [T; OUTPUT_SIZE], fill: T)
+    where
+        T: Copy;
+
+    /// Method form of [`join`](crate::join).
+    #[cfg(feature = "join")]
+    fn join<const RIGHT_SIZE: usize, const RESULT_SIZE: usize>(
+        self,
+        right: [T; RIGHT_SIZE],
+        fill: T,
+    ) -> [T; RESULT_SIZE]
+    where
+        T: Copy;
+
+    /// Method form of [`join_with`](crate::join_with).
+    #[cfg(feature = "join")]
+    fn join_with<F, const RIGHT_SIZE: usize, const RESULT_SIZE: usize>(
+        self,
+        right: [T; RIGHT_SIZE],
+        fill: F,
+    ) -> [T; RESULT_SIZE]
+    where
+        F: FnMut() -> T;
+
+    /// Method form of [`splice`](crate::splice).
+    #[cfg(feature = "splice")]
+    fn splice<const LEFT_SIZE: usize, const RIGHT_SIZE: usize>(
+        self,
+        fill: T,
+    ) -> ([T; LEFT_SIZE], [T; RIGHT_SIZE])
+    where
+        T: Copy;
+
+    /// Method form of [`splice_with`](crate::splice_with).
+    #[cfg(feature = "splice")]
+    fn splice_with<F, const LEFT_SIZE: usize, const RIGHT_SIZE: usize>(
+        self,
+        fill: F,
+    ) -> ([T; LEFT_SIZE], [T; RIGHT_SIZE])
+    where
+        F: FnMut() -> T;
+
+    /// Method form of [`sized_slice`](crate::sized_slice).
+    #[cfg(feature = "slice")]
+    fn sized_slice<const SLICE_SIZE: usize>(
+        self,
+        from: usize,
+        till: usize,
+        fill: T,
+    ) -> [T; SLICE_SIZE]
+    where
+        T: Copy;
+
+    /// Method form of [`sized_slice_with`](crate::sized_slice_with).
+    #[cfg(feature = "slice")]
+    fn sized_slice_with<F, const SLICE_SIZE: usize>(
+        self,
+        from: usize,
+        till: usize,
+        fill: F,
+    ) -> [T; SLICE_SIZE]
+    where
+        F: FnMut() -> T;
+
+    /// Method form of [`superimpose`](crate::superimpose).
+    #[cfg(feature = "superimpose")]
+    fn superimpose<const SUB_SIZE: usize>(
+        self,
+        sub_array: [T; SUB_SIZE],
+        starting_from: usize,
+    ) -> [T; SIZE]
+    where
+        T: Copy;
+
+    /// Method form of [`superimpose_move`](crate::superimpose_move).
+    #[cfg(feature = "superimpose")]
+    fn superimpose_move<const SUB_SIZE: usize>(
+        self,
+        sub_array: [T; SUB_SIZE],
+        starting_from: usize,
+    ) -> [T; SIZE];
+
+    /// Method form of [`superimpose_in_place`](crate::superimpose_in_place).
+    #[cfg(feature = "superimpose")]
+    fn superimpose_in_place<const SUB_SIZE: usize>(
+        &mut self,
+        sub_array: &[T; SUB_SIZE],
+        starting_from: usize,
+    ) where
+        T: Copy;
+
+    /// Method form of [`drift_to_begin`](crate::drift_to_begin).
+    #[cfg(feature = "drift")]
+    fn drift_to_begin(self, from: usize, margin: usize, fill: T) -> [T; SIZE]
+    where
+        T: Copy;
+
+    /// Method form of [`drift_to_begin_with`](crate::drift_to_begin_with).
+    #[cfg(feature = "drift")]
+    fn drift_to_begin_with<F>(self, from: usize, margin: usize, fill: F) -> [T; SIZE]
+    where
+        F: FnMut() -> T;
+
+    /// Method form of [`drift_to_begin_in_place`](crate::drift_to_begin_in_place).
+    #[cfg(feature = "drift")]
+    fn drift_to_begin_in_place(&mut self, from: usize, margin: usize, fill: T)
+    where
+        T: Copy;
+
+    /// Method form of [`drift_to_end`](crate::drift_to_end).
+    #[cfg(feature = "drift")]
+    fn drift_to_end(self, till: usize, margin: usize, fill: T) -> [T; SIZE]
+    where
+        T: Copy;
+
+    /// Method form of [`drift_to_end_with`](crate::drift_to_end_with).
+    #[cfg(feature = "drift")]
+    fn drift_to_end_with<F>(self, till: usize, margin: usize, fill: F) -> [T; SIZE]
+    where
+        F: FnMut() -> T;
+
+    /// Method form of [`drift_to_end_in_place`](crate::drift_to_end_in_place).
+    #[cfg(feature = "drift")]
+    fn drift_to_end_in_place(&mut self, till: usize, margin: usize, fill: T)
+    where
+        T: Copy;
+}
+
+impl<T, const SIZE: usize> ArrayExt<T, SIZE> for [T; SIZE] {
+    #[cfg(feature = "resize")]
+    fn resize<const OUTPUT_SIZE: usize>(self, fill: T) -> [T; OUTPUT_SIZE]
+    where
+        T: Copy,
+    {
+        array_resize(self, fill)
+    }
+
+    #[cfg(feature = "resize")]
+    fn resize_with<F, const OUTPUT_SIZE: usize>(self, fill: F) -> [T; OUTPUT_SIZE]
+    where
+        F: FnMut() -> T,
+    {
+        array_resize_with(self, fill)
+    }
+
+    #[cfg(feature = "resize")]
+    fn resize_into<const OUTPUT_SIZE: usize>(&self, output: &mut [T; OUTPUT_SIZE], fill: T)
+    where
+        T: Copy,
+    {
+        array_resize_into(self, output, fill)
+    }
+
+    #[cfg(feature = "join")]
+    fn join<const RIGHT_SIZE: usize, const RESULT_SIZE: usize>(
+        self,
+        right: [T; RIGHT_SIZE],
+        fill: T,
+    ) -> [T; RESULT_SIZE]
+    where
+        T: Copy,
+    {
+        join(self, right, fill)
+    }
+
+    #[cfg(feature = "join")]
+    fn join_with<F, const RIGHT_SIZE: usize, const RESULT_SIZE: usize>(
+        self,
+        right: [T; RIGHT_SIZE],
+        fill: F,
+    ) -> [T; RESULT_SIZE]
+    where
+        F: FnMut() -> T,
+    {
+        join_with(self, right, fill)
+    }
+
+    #[cfg(feature = "splice")]
+    fn splice<const LEFT_SIZE: usize, const RIGHT_SIZE: usize>(
+        self,
+        fill: T,
+    ) -> ([T; LEFT_SIZE], [T; RIGHT_SIZE])
+    where
+        T: Copy,
+    {
+        splice(self, fill)
+    }
+
+    #[cfg(feature = "splice")]
+    fn splice_with<F, const LEFT_SIZE: usize, const RIGHT_SIZE: usize>(
+        self,
+        fill: F,
+    ) -> ([T; LEFT_SIZE], [T; RIGHT_SIZE])
+    where
+        F: FnMut() -> T,
+    {
+        splice_with(self, fill)
+    }
+
+    #[cfg(feature = "slice")]
+    fn sized_slice<const SLICE_SIZE: usize>(
+        self,
+        from: usize,
+        till: usize,
+        fill: T,
+    ) -> [T; SLICE_SIZE]
+    where
+        T: Copy,
+    {
+        sized_slice(self, from, till, fill)
+    }
+
+    #[cfg(feature = "slice")]
+    fn sized_slice_with<F, const SLICE_SIZE: usize>(
+        self,
+        from: usize,
+        till: usize,
+        fill: F,
+    ) -> [T; SLICE_SIZE]
+    where
+        F: FnMut() -> T,
+    {
+        sized_slice_with(self, from, till, fill)
+    }
+
+    #[cfg(feature = "superimpose")]
+    fn superimpose<const SUB_SIZE: usize>(
+        self,
+        sub_array: [T; SUB_SIZE],
+        starting_from: usize,
+    ) -> [T; SIZE]
+    where
+        T: Copy,
+    {
+        superimpose(self, sub_array, starting_from)
+    }
+
+    #[cfg(feature = "superimpose")]
+    fn superimpose_move<const SUB_SIZE: usize>(
+        self,
+        sub_array: [T; SUB_SIZE],
+        starting_from: usize,
+    ) -> [T; SIZE] {
+        superimpose_move(self, sub_array, starting_from)
+    }
+
+    #[cfg(feature = "superimpose")]
+    fn superimpose_in_place<const SUB_SIZE: usize>(
+        &mut self,
+        sub_array: &[T; SUB_SIZE],
+        starting_from: usize,
+    ) where
+        T: Copy,
+    {
+        superimpose_in_place(self, sub_array, starting_from)
+    }
+
+    #[cfg(feature = "drift")]
+    fn drift_to_begin(self, from: usize, margin: usize, fill: T) -> [T; SIZE]
+    where
+        T: Copy,
+    {
+        drift_to_begin(self, from, margin, fill)
+    }
+
+    #[cfg(feature = "drift")]
+    fn drift_to_begin_with<F>(self, from: usize, margin: usize, fill: F) -> [T; SIZE]
+    where
+        F: FnMut() -> T,
+    {
+        drift_to_begin_with(self, from, margin, fill)
+    }
+
+    #[cfg(feature = "drift")]
+    fn drift_to_begin_in_place(&mut self, from: usize, margin: usize, fill: T)
+    where
+        T: Copy,
+    {
+        drift_to_begin_in_place(self, from, margin, fill)
+    }
+
+    #[cfg(feature = "drift")]
+    fn drift_to_end(self, till: usize, margin: usize, fill: T) -> [T; SIZE]
+    where
+        T: Copy,
+    {
+        drift_to_end(self, till, margin, fill)
+    }
+
+    #[cfg(feature = "drift")]
+    fn drift_to_end_with<F>(self, till: usize, margin: usize, fill: F) -> [T; SIZE]
+    where
+        F: FnMut() -> T,
+    {
+        drift_to_end_with(self, till, margin, fill)
+    }
+
+    #[cfg(feature = "drift")]
+    fn drift_to_end_in_place(&mut self, till: usize, margin: usize, fill: T)
+    where
+        T: Copy,
+    {
+        drift_to_end_in_place(self, till, margin, fill)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::RefCell;
+
+    #[test]
+    #[cfg(all(feature = "join", feature = "splice", feature = "slice"))]
+    fn chained() {
+        let (header, payload): ([u8; 2], [u8; 4]) = [1, 2]
+            .join::<3, 6>([3, 4, 5], 0)
+            .sized_slice::<6>(0, 6, 9)
+            .splice(0);
+        assert_eq!((header, payload), ([1, 2], [3, 4, 5, 0]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "resize", feature = "superimpose", feature = "drift"))]
+    fn chained_with() {
+        let array = [1, 2, 3]
+            .map(RefCell::new)
+            .resize_with::<_, 6>(Default::default)
+            .superimpose_move([RefCell::new(7)], 1)
+            .drift_to_end_with(3, 1, || RefCell::new(9));
+        assert_eq!(array.map(RefCell::into_inner), [9, 9, 1, 7, 3, 9]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "resize", feature = "drift", feature = "superimpose"))]
+    fn matches_free_functions() {
+        let array = [4, 5, 6, 7, 0, 1, 2, 3];
+        assert_eq!(array.resize::<5>(0), array_resize::<_, 8, 5>(array, 0));
+        assert_eq!(
+            array.drift_to_begin(2, 1, 0),
+            drift_to_begin(array, 2, 1, 0)
+        );
+        assert_eq!(array.drift_to_end(3, 2, 0), drift_to_end(array, 3, 2, 0));
+        assert_eq!(array.superimpose([9, 9], 7), superimpose(array, [9, 9], 7));
+
+        let mut in_place = array;
+        in_place.superimpose_in_place(&[9, 9], 7);
+        in_place.drift_to_begin_in_place(2, 1, 0);
+        in_place.drift_to_end_in_place(6, 1, 0);
+        assert_eq!(in_place, [0, 0, 6, 7, 0, 1, 2, 0]);
+
+        let mut output = [0; 4];
+        in_place.resize_into(&mut output, 0);
+        assert_eq!(output, [0, 0, 6, 7]);
+    }
+}
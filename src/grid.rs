@@ -0,0 +1,439 @@
+//! Utilities for two-dimensional arrays.
+//!
+//! A grid is a sized array of rows, `[[T; COLUMNS]; ROWS]`. Positions are given as
+//! `(row, column)` pairs. Just like their one-dimensional counterparts, these utilities never
+//! panic on out of range positions: whatever falls outside of a grid is truncated and whatever is
+//! missing is filled up with the `fill` value.
+
+use crate::ProductOfSizes;
+
+/// Initialize a grid from a closure taking the row and column and outputting the elements.
+///
+/// The closure is called row by row, from left to right.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::initialize_2d;
+///
+/// let multiplication: [[usize; 4]; 3] = initialize_2d(|row, column| (row + 1) * (column + 1));
+/// assert_eq!(multiplication, [[1, 2, 3, 4], [2, 4, 6, 8], [3, 6, 9, 12]]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `f` panics. All elements that were already produced are dropped in
+/// that case.
+pub fn initialize_2d<T, F, const ROWS: usize, const COLUMNS: usize>(
+    mut f: F,
+) -> [[T; COLUMNS]; ROWS]
+where
+    F: FnMut(usize, usize) -> T,
+{
+    ::core::array::from_fn(|row| ::core::array::from_fn(|column| f(row, column)))
+}
+
+/// Swap the rows and columns of a grid.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::transpose;
+///
+/// assert_eq!(
+///     transpose([[1, 2, 3], [4, 5, 6]]),
+///     [[1, 4], [2, 5], [3, 6]]
+/// );
+/// ```
+pub fn transpose<T, const ROWS: usize, const COLUMNS: usize>(
+    grid: [[T; COLUMNS]; ROWS],
+) -> [[T; ROWS]; COLUMNS]
+where
+    T: Copy,
+{
+    ::core::array::from_fn(|column| ::core::array::from_fn(|row| grid[row][column]))
+}
+
+/// Copy a single row out of a grid.
+///
+/// When `row` lies outside of the grid, the `fill` value is returned for every column.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::extract_row;
+///
+/// assert_eq!(extract_row([[1, 2], [3, 4]], 1, 0), [3, 4]);
+/// assert_eq!(extract_row([[1, 2], [3, 4]], 5, 0), [0, 0]);
+/// ```
+pub const fn extract_row<T, const ROWS: usize, const COLUMNS: usize>(
+    grid: [[T; COLUMNS]; ROWS],
+    row: usize,
+    fill: T,
+) -> [T; COLUMNS]
+where
+    T: Copy,
+{
+    if row < ROWS {
+        grid[row]
+    } else {
+        [fill; COLUMNS]
+    }
+}
+
+/// Copy a single column out of a grid.
+///
+/// When `column` lies outside of the grid, the `fill` value is returned for every row.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::extract_column;
+///
+/// assert_eq!(extract_column([[1, 2], [3, 4]], 1, 0), [2, 4]);
+/// assert_eq!(extract_column([[1, 2], [3, 4]], 5, 0), [0, 0]);
+/// ```
+pub const fn extract_column<T, const ROWS: usize, const COLUMNS: usize>(
+    grid: [[T; COLUMNS]; ROWS],
+    column: usize,
+    fill: T,
+) -> [T; ROWS]
+where
+    T: Copy,
+{
+    let mut buffer = [fill; ROWS];
+    if column >= COLUMNS {
+        return buffer;
+    }
+
+    let mut row = 0;
+    while row < ROWS {
+        buffer[row] = grid[row][column];
+        row += 1;
+    }
+    buffer
+}
+
+/// Create a sized sub-grid of a grid.
+///
+/// Copies the part of `original` with its top-left corner at `from`, a `(row, column)` pair. The
+/// size of the sub-grid is given by the output type. Elements of the sub-grid which lie outside of
+/// `original` are filled with the `fill` value.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::sized_slice_2d;
+///
+/// let grid = [
+///     [1, 2, 3],
+///     [4, 5, 6],
+///     [7, 8, 9],
+/// ];
+///
+/// assert_eq!(sized_slice_2d(grid, (1, 1), 0), [[5, 6], [8, 9]]);
+/// assert_eq!(sized_slice_2d(grid, (2, 1), 0), [[8, 9, 0], [0, 0, 0]]);
+/// ```
+pub const fn sized_slice_2d<
+    T,
+    const ORIGINAL_ROWS: usize,
+    const ORIGINAL_COLUMNS: usize,
+    const SLICE_ROWS: usize,
+    const SLICE_COLUMNS: usize,
+>(
+    original: [[T; ORIGINAL_COLUMNS]; ORIGINAL_ROWS],
+    from: (usize, usize),
+    fill: T,
+) -> [[T; SLICE_COLUMNS]; SLICE_ROWS]
+where
+    T: Copy,
+{
+    let mut buffer = [[fill; SLICE_COLUMNS]; SLICE_ROWS];
+
+    let mut row = 0;
+    while row < SLICE_ROWS && from.0.saturating_add(row) < ORIGINAL_ROWS {
+        let mut column = 0;
+        while column < SLICE_COLUMNS && from.1.saturating_add(column) < ORIGINAL_COLUMNS {
+            buffer[row][column] = original[from.0 + row][from.1 + column];
+            column += 1;
+        }
+        row += 1;
+    }
+    buffer
+}
+
+/// Superimpose a sized `sub_grid` upon another `main_grid` at position `starting_from`.
+///
+/// Copies `sub_grid` into `main_grid` with its top-left corner at `starting_from`, a
+/// `(row, column)` pair. The parts of `sub_grid` which fall outside of `main_grid` are truncated.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::superimpose_2d;
+///
+/// let canvas = [[0; 4]; 3];
+/// let sprite = [[1, 2], [3, 4]];
+///
+/// assert_eq!(
+///     superimpose_2d(canvas, sprite, (1, 1)),
+///     [[0, 0, 0, 0], [0, 1, 2, 0], [0, 3, 4, 0]]
+/// );
+/// assert_eq!(
+///     superimpose_2d(canvas, sprite, (2, 3)),
+///     [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 1]]
+/// );
+/// ```
+pub const fn superimpose_2d<
+    T,
+    const MAIN_ROWS: usize,
+    const MAIN_COLUMNS: usize,
+    const SUB_ROWS: usize,
+    const SUB_COLUMNS: usize,
+>(
+    mut main_grid: [[T; MAIN_COLUMNS]; MAIN_ROWS],
+    sub_grid: [[T; SUB_COLUMNS]; SUB_ROWS],
+    starting_from: (usize, usize),
+) -> [[T; MAIN_COLUMNS]; MAIN_ROWS]
+where
+    T: Copy,
+{
+    let mut row = 0;
+    while row < SUB_ROWS && starting_from.0.saturating_add(row) < MAIN_ROWS {
+        let mut column = 0;
+        while column < SUB_COLUMNS && starting_from.1.saturating_add(column) < MAIN_COLUMNS {
+            main_grid[starting_from.0 + row][starting_from.1 + column] = sub_grid[row][column];
+            column += 1;
+        }
+        row += 1;
+    }
+    main_grid
+}
+
+/// Flatten a grid into a sized array, row by row.
+///
+/// If the grid holds more elements than fit in the output array, the remaining elements are
+/// truncated. If it holds fewer, the rest of the output array is filled with the `fill` value.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::flatten;
+///
+/// assert_eq!(flatten([[1, 2], [3, 4], [5, 6]], 0), [1, 2, 3, 4, 5, 6]);
+/// assert_eq!(flatten([[1, 2], [3, 4], [5, 6]], 0), [1, 2, 3, 4]);
+/// assert_eq!(flatten([[1, 2], [3, 4], [5, 6]], 0), [1, 2, 3, 4, 5, 6, 0]);
+/// ```
+pub const fn flatten<T, const ROWS: usize, const COLUMNS: usize, const OUTPUT_SIZE: usize>(
+    grid: [[T; COLUMNS]; ROWS],
+    fill: T,
+) -> [T; OUTPUT_SIZE]
+where
+    T: Copy,
+{
+    let mut buffer = [fill; OUTPUT_SIZE];
+
+    let mut i = 0;
+    while i < OUTPUT_SIZE && i < ROWS.saturating_mul(COLUMNS) {
+        buffer[i] = grid[i / COLUMNS][i % COLUMNS];
+        i += 1;
+    }
+    buffer
+}
+
+/// Flatten a grid into a sized array of exactly the amount of elements in the grid.
+///
+/// The strict counterpart of [`flatten`](crate::flatten). It is verified at compile time that
+/// `OUTPUT_SIZE` is equal to `ROWS * COLUMNS`, so no elements are ever truncated or filled up.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::flatten_exact;
+///
+/// let blocks = [[0xDE, 0xAD], [0xBE, 0xEF]];
+/// assert_eq!(flatten_exact(blocks), [0xDE, 0xAD, 0xBE, 0xEF]);
+/// ```
+///
+/// A mismatch between the sizes is a build error.
+///
+/// ```compile_fail
+/// use array_utils::flatten_exact;
+///
+/// let flat: [u8; 5] = flatten_exact([[1, 2], [3, 4]]);
+/// ```
+pub fn flatten_exact<T, const ROWS: usize, const COLUMNS: usize, const OUTPUT_SIZE: usize>(
+    grid: [[T; COLUMNS]; ROWS],
+) -> [T; OUTPUT_SIZE]
+where
+    T: Copy,
+{
+    let () = ProductOfSizes::<ROWS, COLUMNS, OUTPUT_SIZE>::VALID;
+
+    ::core::array::from_fn(|i| grid[i / COLUMNS][i % COLUMNS])
+}
+
+/// Split a sized array into a grid of fixed-size chunks.
+///
+/// The elements of `array` are distributed row by row. If `array` holds fewer elements than the
+/// grid, such as when its size is not a multiple of `COLUMNS`, the rest of the grid is filled
+/// with the `fill` value. If it holds more, the remaining elements are truncated.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::chunk;
+///
+/// // Frame a buffer into blocks of 4 bytes, padding the last block.
+/// let blocks: [[u8; 4]; 3] = chunk([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 0);
+/// assert_eq!(blocks, [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 0, 0]]);
+/// ```
+pub const fn chunk<T, const SIZE: usize, const ROWS: usize, const COLUMNS: usize>(
+    array: [T; SIZE],
+    fill: T,
+) -> [[T; COLUMNS]; ROWS]
+where
+    T: Copy,
+{
+    let mut buffer = [[fill; COLUMNS]; ROWS];
+
+    let mut i = 0;
+    while i < SIZE && i < ROWS.saturating_mul(COLUMNS) {
+        buffer[i / COLUMNS][i % COLUMNS] = array[i];
+        i += 1;
+    }
+    buffer
+}
+
+/// Split a sized array into a grid of fixed-size chunks holding exactly its elements.
+///
+/// The strict counterpart of [`chunk`](crate::chunk). It is verified at compile time that `SIZE`
+/// is equal to `ROWS * COLUMNS`, so no elements are ever truncated or filled up.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::chunk_exact;
+///
+/// let blocks: [[u8; 2]; 3] = chunk_exact([1, 2, 3, 4, 5, 6]);
+/// assert_eq!(blocks, [[1, 2], [3, 4], [5, 6]]);
+/// ```
+///
+/// A mismatch between the sizes is a build error.
+///
+/// ```compile_fail
+/// use array_utils::chunk_exact;
+///
+/// let blocks: [[u8; 4]; 2] = chunk_exact([1, 2, 3, 4, 5, 6]);
+/// ```
+pub fn chunk_exact<T, const SIZE: usize, const ROWS: usize, const COLUMNS: usize>(
+    array: [T; SIZE],
+) -> [[T; COLUMNS]; ROWS]
+where
+    T: Copy,
+{
+    let () = ProductOfSizes::<ROWS, COLUMNS, SIZE>::VALID;
+
+    ::core::array::from_fn(|row| ::core::array::from_fn(|column| array[row * COLUMNS + column]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: [[u8; 3]; 2] = [[1, 2, 3], [4, 5, 6]];
+
+    #[test]
+    fn init_2d() {
+        let mut calls = 0;
+        let order: [[usize; 3]; 2] = initialize_2d(|_, _| {
+            calls += 1;
+            calls
+        });
+        assert_eq!(order, [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(initialize_2d::<u8, _, 0, 3>(|_, _| 1), [[0u8; 3]; 0]);
+        assert_eq!(initialize_2d::<u8, _, 2, 0>(|_, _| 1), [[], []]);
+    }
+
+    #[test]
+    fn transposing() {
+        assert_eq!(transpose(GRID), [[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(transpose(transpose(GRID)), GRID);
+        assert_eq!(transpose::<u8, 0, 3>([]), [[], [], []]);
+        assert_eq!(transpose::<u8, 2, 0>([[], []]), [[0u8; 2]; 0]);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        assert_eq!(extract_row(GRID, 0, 0), [1, 2, 3]);
+        assert_eq!(extract_row(GRID, 2, 0), [0, 0, 0]);
+        assert_eq!(extract_row(GRID, usize::MAX, 0), [0, 0, 0]);
+        assert_eq!(extract_column(GRID, 2, 0), [3, 6]);
+        assert_eq!(extract_column(GRID, 3, 0), [0, 0]);
+        assert_eq!(extract_column(GRID, usize::MAX, 0), [0, 0]);
+    }
+
+    #[test]
+    fn slicing() {
+        assert_eq!(sized_slice_2d(GRID, (0, 0), 0), GRID);
+        assert_eq!(sized_slice_2d(GRID, (0, 1), 0), [[2, 3]]);
+        assert_eq!(
+            sized_slice_2d(GRID, (1, 2), 0),
+            [[6, 0, 0], [0, 0, 0], [0, 0, 0]]
+        );
+        assert_eq!(sized_slice_2d(GRID, (usize::MAX, 0), 9), [[9; 2]; 2]);
+        assert_eq!(sized_slice_2d(GRID, (0, usize::MAX), 9), [[9; 2]; 2]);
+    }
+
+    #[test]
+    fn superimposing() {
+        assert_eq!(
+            superimpose_2d([[0; 3]; 2], [[7, 8]], (1, 2)),
+            [[0, 0, 0], [0, 0, 7]]
+        );
+        assert_eq!(superimpose_2d([[0; 3]; 2], GRID, (0, 0)), GRID);
+        assert_eq!(superimpose_2d(GRID, [[0; 4]; 4], (2, 0)), GRID);
+        assert_eq!(superimpose_2d(GRID, [[0; 4]; 4], (0, usize::MAX)), GRID);
+        assert_eq!(superimpose_2d(GRID, [[0; 4]; 4], (usize::MAX, 0)), GRID);
+    }
+
+    #[test]
+    fn flattening() {
+        assert_eq!(flatten(GRID, 0), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(flatten(GRID, 0), [1, 2, 3, 4]);
+        assert_eq!(flatten(GRID, 0), [1, 2, 3, 4, 5, 6, 0, 0]);
+        assert_eq!(flatten([[0u8; 0]; 3], 9), [9, 9]);
+        assert_eq!(flatten_exact(GRID), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(flatten_exact([[0u8; 0]; 3]), []);
+    }
+
+    #[test]
+    fn chunking() {
+        assert_eq!(chunk([1, 2, 3, 4, 5, 6], 0), GRID);
+        assert_eq!(chunk([1, 2, 3, 4, 5], 0), [[1, 2, 3], [4, 5, 0]]);
+        assert_eq!(chunk([1, 2, 3, 4, 5, 6, 7], 0), [[1, 2], [3, 4]]);
+        assert_eq!(chunk([1, 2, 3], 0), [[0u8; 0]; 2]);
+        assert_eq!(chunk_exact([1, 2, 3, 4, 5, 6]), GRID);
+        assert_eq!(chunk_exact::<u8, 0, 4, 0>([]), [[]; 4]);
+
+        let flat: [u8; 6] = flatten_exact(GRID);
+        let regrouped: [[u8; 2]; 3] = chunk_exact(flat);
+        assert_eq!(regrouped, [[1, 2], [3, 4], [5, 6]]);
+    }
+
+    #[test]
+    fn const_contexts() {
+        const ROW: [u8; 3] = extract_row(GRID, 1, 0);
+        const COLUMN: [u8; 2] = extract_column(GRID, 0, 0);
+        const SLICE: [[u8; 2]; 1] = sized_slice_2d(GRID, (1, 1), 0);
+        const BLIT: [[u8; 3]; 2] = superimpose_2d(GRID, [[0]], (1, 1));
+        const FLAT: [u8; 6] = flatten(GRID, 0);
+        const CHUNKS: [[u8; 3]; 2] = chunk(FLAT, 0);
+
+        assert_eq!(ROW, [4, 5, 6]);
+        assert_eq!(COLUMN, [1, 4]);
+        assert_eq!(SLICE, [[5, 6]]);
+        assert_eq!(BLIT, [[1, 2, 3], [4, 0, 6]]);
+        assert_eq!(CHUNKS, GRID);
+    }
+}
@@ -0,0 +1,221 @@
+//! Inserting and removing elements in the middle of sized arrays.
+
+use core::mem;
+
+/// Insert an element at `index`, shifting all elements after it one place to the right.
+///
+/// As the size of the array is fixed, the last element is pushed off the end and returned as
+/// [`Some`] along with the new array. If `index` is greater than or equal to the size of the
+/// array, nothing is inserted: the array is returned unchanged and `value` is handed back as
+/// [`Some`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::insert_at;
+///
+/// assert_eq!(insert_at([1, 2, 3, 4], 1, 9), ([1, 9, 2, 3], Some(4)));
+/// assert_eq!(insert_at([1, 2, 3, 4], 4, 9), ([1, 2, 3, 4], Some(9)));
+/// ```
+pub fn insert_at<T, const SIZE: usize>(
+    mut array: [T; SIZE],
+    index: usize,
+    value: T,
+) -> ([T; SIZE], Option<T>) {
+    if index >= SIZE {
+        return (array, Some(value));
+    }
+
+    let last = mem::replace(&mut array[SIZE - 1], value);
+    array[index..].rotate_right(1);
+    (array, Some(last))
+}
+
+/// Remove the element at `index`, shifting all elements after it one place to the left.
+///
+/// The freed place at the end of the array is filled with the `fill` value. The removed element
+/// is returned along with the new array. If `index` is out of range, the array is returned
+/// unchanged along with `fill` itself.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::remove_at;
+///
+/// assert_eq!(remove_at([1, 2, 3, 4], 1, 0), ([1, 3, 4, 0], 2));
+/// assert_eq!(remove_at([1, 2, 3, 4], 7, 0), ([1, 2, 3, 4], 0));
+/// ```
+pub fn remove_at<T, const SIZE: usize>(
+    mut array: [T; SIZE],
+    index: usize,
+    fill: T,
+) -> ([T; SIZE], T) {
+    if index >= SIZE {
+        return (array, fill);
+    }
+
+    let removed = mem::replace(&mut array[index], fill);
+    array[index..].rotate_left(1);
+    (array, removed)
+}
+
+/// Insert the elements of `slice` at `index`, shifting all elements after it to the right.
+///
+/// Elements which are shifted past the end of the array are dropped. If `slice` does not fit in
+/// the array after `index`, it is truncated. If `index` is out of range, the array is returned
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::insert_slice_at;
+///
+/// assert_eq!(insert_slice_at([1, 2, 3, 4, 5], 1, &[8, 9]), [1, 8, 9, 2, 3]);
+/// assert_eq!(insert_slice_at([1, 2, 3, 4, 5], 3, &[7, 8, 9]), [1, 2, 3, 7, 8]);
+/// ```
+pub const fn insert_slice_at<T, const SIZE: usize>(
+    array: [T; SIZE],
+    index: usize,
+    slice: &[T],
+) -> [T; SIZE]
+where
+    T: Copy,
+{
+    let mut buffer = array;
+
+    let mut i = index;
+    while i < SIZE {
+        buffer[i] = if i - index < slice.len() {
+            slice[i - index]
+        } else {
+            array[i - slice.len()]
+        };
+        i += 1;
+    }
+    buffer
+}
+
+/// Remove the elements in the range `from..till`, shifting all elements after it to the left.
+///
+/// The freed places at the end of the array are filled with the `fill` value. Parts of the range
+/// which lie outside of the array are ignored, just like with [`sized_slice`](crate::sized_slice).
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::remove_range;
+///
+/// assert_eq!(remove_range([1, 2, 3, 4, 5], 1, 3, 0), [1, 4, 5, 0, 0]);
+/// assert_eq!(remove_range([1, 2, 3, 4, 5], 3, 9, 0), [1, 2, 3, 0, 0]);
+/// ```
+pub const fn remove_range<T, const SIZE: usize>(
+    array: [T; SIZE],
+    from: usize,
+    till: usize,
+    fill: T,
+) -> [T; SIZE]
+where
+    T: Copy,
+{
+    let mut buffer = array;
+    if from >= till || from >= SIZE {
+        return buffer;
+    }
+
+    let removed = if till < SIZE {
+        till - from
+    } else {
+        SIZE - from
+    };
+    let mut i = from;
+    while i < SIZE {
+        buffer[i] = if i + removed < SIZE {
+            array[i + removed]
+        } else {
+            fill
+        };
+        i += 1;
+    }
+    buffer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Tracked;
+    use core::cell::Cell;
+
+    #[test]
+    fn single_elements() {
+        assert_eq!(insert_at([1, 2, 3], 0, 0), ([0, 1, 2], Some(3)));
+        assert_eq!(insert_at([1, 2, 3], 2, 0), ([1, 2, 0], Some(3)));
+        assert_eq!(insert_at([1, 2, 3], 3, 0), ([1, 2, 3], Some(0)));
+        assert_eq!(insert_at([1, 2, 3], usize::MAX, 0), ([1, 2, 3], Some(0)));
+        assert_eq!(insert_at([], 0, 0), ([], Some(0)));
+
+        assert_eq!(remove_at([1, 2, 3], 0, 0), ([2, 3, 0], 1));
+        assert_eq!(remove_at([1, 2, 3], 2, 0), ([1, 2, 0], 3));
+        assert_eq!(remove_at([1, 2, 3], usize::MAX, 0), ([1, 2, 3], 0));
+        assert_eq!(remove_at([], 0, 0), ([], 0));
+
+        let (array, removed) = remove_at(insert_at([1, 2, 3, 4], 2, 9).0, 2, 4);
+        assert_eq!((array, removed), ([1, 2, 3, 4], 9));
+    }
+
+    #[test]
+    fn non_copy() {
+        let drops = Cell::new(0);
+        let array = [0, 1, 2].map(|value| Tracked(value, &drops));
+
+        let (array, pushed_off) = insert_at(array, 1, Tracked(9, &drops));
+        assert_eq!(pushed_off.as_ref().map(|element| element.0), Some(2));
+        assert_eq!(array.each_ref().map(|element| element.0), [0, 9, 1]);
+        drop(pushed_off);
+
+        let (array, removed) = remove_at(array, 0, Tracked(7, &drops));
+        assert_eq!(removed.0, 0);
+        assert_eq!(array.each_ref().map(|element| element.0), [9, 1, 7]);
+        drop(removed);
+
+        assert_eq!(drops.get(), 2);
+        let (array, rejected) = insert_at(array, 3, Tracked(8, &drops));
+        assert_eq!(rejected.as_ref().map(|element| element.0), Some(8));
+        assert_eq!(array.each_ref().map(|element| element.0), [9, 1, 7]);
+        assert_eq!(drops.get(), 2);
+        drop(rejected);
+        drop(array);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn slices() {
+        assert_eq!(insert_slice_at([1, 2, 3], 0, &[]), [1, 2, 3]);
+        assert_eq!(insert_slice_at([1, 2, 3], 0, &[7]), [7, 1, 2]);
+        assert_eq!(insert_slice_at([1, 2, 3], 1, &[7, 8, 9, 10]), [1, 7, 8]);
+        assert_eq!(insert_slice_at([1, 2, 3], 3, &[7]), [1, 2, 3]);
+        assert_eq!(insert_slice_at([1, 2, 3], usize::MAX, &[7]), [1, 2, 3]);
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(remove_range([1, 2, 3], 0, 0, 0), [1, 2, 3]);
+        assert_eq!(remove_range([1, 2, 3], 2, 1, 0), [1, 2, 3]);
+        assert_eq!(remove_range([1, 2, 3], 0, 3, 0), [0, 0, 0]);
+        assert_eq!(remove_range([1, 2, 3], 1, usize::MAX, 0), [1, 0, 0]);
+        assert_eq!(
+            remove_range([1, 2, 3], usize::MAX, usize::MAX, 0),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            remove_range(insert_slice_at([1, 2, 3, 4, 5], 1, &[8, 9]), 1, 3, 0),
+            [1, 2, 3, 0, 0]
+        );
+    }
+
+    #[test]
+    fn const_contexts() {
+        const INSERTED: [u8; 4] = insert_slice_at([1, 2, 3, 4], 2, &[0]);
+        const REMOVED: [u8; 4] = remove_range(INSERTED, 0, 1, 0);
+        assert_eq!(REMOVED, [2, 0, 3, 0]);
+    }
+}
@@ -0,0 +1,227 @@
+//! Interleaving and de-interleaving channels of samples.
+//!
+//! Interleaved samples of `CHANNELS` channels are stored as a flat array in which every group of
+//! `CHANNELS` consecutive elements holds one sample of each channel, such as the left and right
+//! samples of stereo audio. The channels themselves are stored as `[[T; SAMPLES]; CHANNELS]`.
+
+use crate::ProductOfSizes;
+
+/// Interleave the samples of multiple channels into a single sized array.
+///
+/// If the channels hold more samples than fit in the output array, the remaining samples are
+/// truncated. If they hold fewer, the rest of the output array is filled with the `fill` value.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::interleave;
+///
+/// let channels = [[1, 2, 3], [10, 20, 30], [100, 200, 300]];
+/// assert_eq!(interleave(channels, 0), [1, 10, 100, 2, 20, 200, 3, 30, 300]);
+/// assert_eq!(interleave(channels, 0), [1, 10, 100, 2]);
+/// ```
+pub const fn interleave<T, const SAMPLES: usize, const CHANNELS: usize, const OUTPUT_SIZE: usize>(
+    channels: [[T; SAMPLES]; CHANNELS],
+    fill: T,
+) -> [T; OUTPUT_SIZE]
+where
+    T: Copy,
+{
+    let mut buffer = [fill; OUTPUT_SIZE];
+
+    let mut i = 0;
+    while i < OUTPUT_SIZE && i < SAMPLES.saturating_mul(CHANNELS) {
+        buffer[i] = channels[i % CHANNELS][i / CHANNELS];
+        i += 1;
+    }
+    buffer
+}
+
+/// Interleave the samples of multiple channels into a sized array of exactly the combined size.
+///
+/// The strict counterpart of [`interleave`](crate::interleave). It is verified at compile time
+/// that `OUTPUT_SIZE` is equal to `SAMPLES * CHANNELS`, so no samples are ever truncated or filled
+/// up.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::interleave_exact;
+///
+/// assert_eq!(interleave_exact([[1, 2], [3, 4]]), [1, 3, 2, 4]);
+/// ```
+///
+/// A mismatch between the sizes is a build error.
+///
+/// ```compile_fail
+/// use array_utils::interleave_exact;
+///
+/// let samples: [u8; 5] = interleave_exact([[1, 2], [3, 4]]);
+/// ```
+pub fn interleave_exact<T, const SAMPLES: usize, const CHANNELS: usize, const OUTPUT_SIZE: usize>(
+    channels: [[T; SAMPLES]; CHANNELS],
+) -> [T; OUTPUT_SIZE]
+where
+    T: Copy,
+{
+    let () = ProductOfSizes::<SAMPLES, CHANNELS, OUTPUT_SIZE>::VALID;
+
+    ::core::array::from_fn(|i| channels[i % CHANNELS][i / CHANNELS])
+}
+
+/// Interleave the samples of two channels into a single sized array.
+///
+/// Works the same as [`interleave`](crate::interleave) with the channels `[left, right]`.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::interleave_pair;
+///
+/// assert_eq!(interleave_pair([1, 2, 3], [4, 5, 6], 0), [1, 4, 2, 5, 3, 6]);
+/// assert_eq!(interleave_pair([1, 2], [4, 5], 0), [1, 4, 2, 5, 0]);
+/// ```
+pub const fn interleave_pair<T, const SAMPLES: usize, const OUTPUT_SIZE: usize>(
+    left: [T; SAMPLES],
+    right: [T; SAMPLES],
+    fill: T,
+) -> [T; OUTPUT_SIZE]
+where
+    T: Copy,
+{
+    interleave([left, right], fill)
+}
+
+/// Split interleaved samples into multiple channels.
+///
+/// If `samples` holds fewer elements than all channels together, the missing samples are filled
+/// with the `fill` value. If it holds more, the remaining elements are truncated.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::deinterleave;
+///
+/// let samples = [1, 10, 100, 2, 20, 200, 3, 30];
+/// assert_eq!(deinterleave(samples, 0), [[1, 2, 3], [10, 20, 30], [100, 200, 0]]);
+/// ```
+pub const fn deinterleave<T, const SIZE: usize, const SAMPLES: usize, const CHANNELS: usize>(
+    samples: [T; SIZE],
+    fill: T,
+) -> [[T; SAMPLES]; CHANNELS]
+where
+    T: Copy,
+{
+    let mut buffer = [[fill; SAMPLES]; CHANNELS];
+
+    let mut i = 0;
+    while i < SIZE && i < SAMPLES.saturating_mul(CHANNELS) {
+        buffer[i % CHANNELS][i / CHANNELS] = samples[i];
+        i += 1;
+    }
+    buffer
+}
+
+/// Split interleaved samples into multiple channels holding exactly all samples.
+///
+/// The strict counterpart of [`deinterleave`](crate::deinterleave). It is verified at compile
+/// time that `SIZE` is equal to `SAMPLES * CHANNELS`, so no samples are ever truncated or filled
+/// up.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::deinterleave_exact;
+///
+/// let [left, right]: [[i16; 3]; 2] = deinterleave_exact([1, -1, 2, -2, 3, -3]);
+/// assert_eq!((left, right), ([1, 2, 3], [-1, -2, -3]));
+/// ```
+///
+/// A mismatch between the sizes is a build error.
+///
+/// ```compile_fail
+/// use array_utils::deinterleave_exact;
+///
+/// let channels: [[u8; 2]; 2] = deinterleave_exact([1, 2, 3, 4, 5]);
+/// ```
+pub fn deinterleave_exact<T, const SIZE: usize, const SAMPLES: usize, const CHANNELS: usize>(
+    samples: [T; SIZE],
+) -> [[T; SAMPLES]; CHANNELS]
+where
+    T: Copy,
+{
+    let () = ProductOfSizes::<SAMPLES, CHANNELS, SIZE>::VALID;
+
+    ::core::array::from_fn(|channel| {
+        ::core::array::from_fn(|sample| samples[sample * CHANNELS + channel])
+    })
+}
+
+/// Split interleaved samples into two channels.
+///
+/// Works the same as [`deinterleave`](crate::deinterleave) with two channels.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::deinterleave_pair;
+///
+/// assert_eq!(deinterleave_pair([1, 4, 2, 5, 3], 0), ([1, 2, 3], [4, 5, 0]));
+/// ```
+pub const fn deinterleave_pair<T, const SIZE: usize, const SAMPLES: usize>(
+    samples: [T; SIZE],
+    fill: T,
+) -> ([T; SAMPLES], [T; SAMPLES])
+where
+    T: Copy,
+{
+    let [left, right] = deinterleave(samples, fill);
+    (left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANNELS: [[u8; 3]; 2] = [[1, 2, 3], [4, 5, 6]];
+    const SAMPLES: [u8; 6] = [1, 4, 2, 5, 3, 6];
+
+    #[test]
+    fn interleaving() {
+        assert_eq!(interleave(CHANNELS, 0), SAMPLES);
+        assert_eq!(interleave(CHANNELS, 0), [1, 4, 2]);
+        assert_eq!(interleave(CHANNELS, 0), [1, 4, 2, 5, 3, 6, 0]);
+        assert_eq!(interleave([[0u8; 0]; 2], 9), [9, 9]);
+        assert_eq!(interleave([[1u8; 2]; 0], 9), [9]);
+        assert_eq!(interleave_exact(CHANNELS), SAMPLES);
+        assert_eq!(interleave_pair(CHANNELS[0], CHANNELS[1], 0), SAMPLES);
+    }
+
+    #[test]
+    fn deinterleaving() {
+        assert_eq!(deinterleave(SAMPLES, 0), CHANNELS);
+        assert_eq!(deinterleave(SAMPLES, 0), [[1, 2], [4, 5]]);
+        assert_eq!(deinterleave(SAMPLES, 0), [[1, 5], [4, 3], [2, 6]]);
+        assert_eq!(deinterleave(SAMPLES, 0), [[1, 2, 3, 0], [4, 5, 6, 0]]);
+        assert_eq!(deinterleave([1, 2, 3], 0), [[0u8; 0]; 2]);
+        assert_eq!(deinterleave_exact(SAMPLES), CHANNELS);
+        assert_eq!(deinterleave_pair(SAMPLES, 0), (CHANNELS[0], CHANNELS[1]));
+    }
+
+    #[test]
+    fn round_trip() {
+        let channels: [[u8; 4]; 3] = deinterleave_exact(interleave_exact::<_, 4, 3, 12>([
+            [1, 2, 3, 4],
+            [5, 6, 7, 8],
+            [9, 10, 11, 12],
+        ]));
+        assert_eq!(channels, [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+    }
+
+    #[test]
+    fn const_contexts() {
+        const INTERLEAVED: [u8; 6] = interleave_pair([1, 2, 3], [4, 5, 6], 0);
+        const SPLIT: ([u8; 3], [u8; 3]) = deinterleave_pair(INTERLEAVED, 0);
+        assert_eq!(SPLIT, ([1, 2, 3], [4, 5, 6]));
+    }
+}
@@ -13,13 +13,56 @@
 //! either result in data truncating or an array being fill up with extra data. The bounds,
 //! specific behaviors and other important details are documented in each function's page.
 //!
+//! When truncating or filling up is not acceptable, the checked counterparts
+//! [`try_array_resize`](crate::try_array_resize), [`try_superimpose`](crate::try_superimpose),
+//! [`try_join`](crate::try_join), [`try_splice`](crate::try_splice) and
+//! [`try_sized_slice`](crate::try_sized_slice) return a [`SizeError`](crate::SizeError) instead.
+//!
 //! ## Initialize
 //!
-//! This feature provides 4 utility functions. These are
+//! This feature provides 5 utility functions. These are
 //! [`initialize_from`](crate::initialize_from), [`initialize_till`](crate::initialize_till),
-//! [`initialize_from_option`](crate::initialize_from_option) and
-//! [`initialize_from_result`](crate::initialize_from_result). All these functions provide an
+//! [`initialize_from_option`](crate::initialize_from_option),
+//! [`initialize_from_result`](crate::initialize_from_result) and
+//! [`initialize_scan`](crate::initialize_scan). All these functions provide an
 //! simpler ways to initialize sized array using closures, as can be seen in their documentation.
+//! The closures may carry state between calls, so a pseudo-random generator or a parser can be
+//! used directly. When the reason for a failed initialization matters,
+//! [`try_initialize_from`](crate::try_initialize_from) returns the closure's error together with
+//! the index and the partially initialized array.
+//!
+//! ## Collect
+//!
+//! Where the initializers build arrays from index closures, [`collect_array`](crate::collect_array)
+//! builds them from iterators, such as byte streams or parsed tokens. The strict
+//! [`try_collect_array`](crate::try_collect_array) returns a [`CollectError`](crate::CollectError)
+//! when the iterator yields too few or too many elements. Both are also available as methods on
+//! iterators through the [`IteratorExt`](crate::IteratorExt) trait.
+//!
+//! ## Map
+//!
+//! Transformations which keep the size of an array in its type are provided by
+//! [`map_indexed`](crate::map_indexed), [`zip`](crate::zip), [`zip_with`](crate::zip_with),
+//! [`unzip`](crate::unzip) and the fallible [`try_map`](crate::try_map), which returns the first
+//! error along with the index of the element that caused it.
+//!
+//! ## Sort
+//!
+//! Small arrays, such as windows of sensor readings or priority tables, are sorted without
+//! allocating by [`sorted`](crate::sorted) and [`sorted_by_key`](crate::sorted_by_key).
+//! [`is_sorted`](crate::is_sorted) checks the order and [`argsort`](crate::argsort) returns the
+//! indices that would sort an array. Arrays of primitive integers can be sorted at compile time
+//! with the `const fn` sorting networks, such as [`sorted_u32`](crate::sorted_u32).
+//!
+//! ## Search
+//!
+//! Sized arrays are searched for sub-arrays, such as sync words in received frames, with
+//! [`find_subarray`](crate::find_subarray), [`rfind_subarray`](crate::rfind_subarray) and
+//! [`count_occurrences`](crate::count_occurrences). Single elements are found with
+//! [`position`](crate::position) and, in sorted arrays, with
+//! [`binary_search`](crate::binary_search). Arrays of primitive integers can be searched at
+//! compile time with the `const fn` variants, such as
+//! [`find_subarray_u8`](crate::find_subarray_u8).
 //!
 //! ## Drift / Superimpose
 //!
@@ -31,6 +74,23 @@
 //! [`superimpose`](crate::superimpose). There is also the more general form of [`superimpose`](crate::superimpose), which allows for one
 //! sized array to be superimposed upon another.
 //!
+//! ## Insert
+//!
+//! Elements are inserted into and removed from the middle of a sized array with
+//! [`insert_at`](crate::insert_at) and [`remove_at`](crate::remove_at), which shift the tail of
+//! the array and return the element that was pushed off or removed. Their range versions
+//! [`insert_slice_at`](crate::insert_slice_at) and [`remove_range`](crate::remove_range) are
+//! `const fn`s and follow the same truncating and filling rules as the other utilities.
+//!
+//! ## Rotate
+//!
+//! Where drifting drops the elements which leave the array, [`rotate_left`](crate::rotate_left)
+//! and [`rotate_right`](crate::rotate_right) move them around to the other end. The amount is
+//! taken modulo the size of the array, so they never panic. The
+//! [`rotate_left_const`](crate::rotate_left_const) and
+//! [`rotate_right_const`](crate::rotate_right_const) variants take the amount as a const generic
+//! parameter and are `const fn`s.
+//!
 //! ## Slice / Resize
 //!
 //! Ordinary slices of sized array have the disadvantage of either losing size metadata or needing
@@ -44,14 +104,102 @@
 //!
 //! The [`splice`](crate::splice) and [`join`](crate::join) utilities are basically more optimized combinations of
 //! [`sized_slice`](crate::sized_slice) and [`superimpose`](crate::superimpose). Making splicing and joining arrays at specific indices can
-//! be very handy for dealing with packet and data streams.
+//! be very handy for dealing with packet and data streams. The strict [`join_exact`](crate::join_exact)
+//! and [`splice_exact`](crate::splice_exact) variants verify at compile time that the sizes of the
+//! arrays add up, so no `fill` value is needed.
+//!
+//! ## Interleave
+//!
+//! Multi-channel data, such as audio or ADC samples, is converted between interleaved samples and
+//! per-channel arrays by [`interleave`](crate::interleave) and
+//! [`deinterleave`](crate::deinterleave), along with their two-channel shorthands
+//! [`interleave_pair`](crate::interleave_pair) and [`deinterleave_pair`](crate::deinterleave_pair).
+//! Like [`join`](crate::join) and [`splice`](crate::splice) they truncate or use a `fill` value,
+//! while the [`interleave_exact`](crate::interleave_exact) and
+//! [`deinterleave_exact`](crate::deinterleave_exact) variants verify the sizes at compile time.
+//!
+//! ## Vec
+//!
+//! The [`ArrayVec`](crate::ArrayVec) type is a heapless vector with a fixed capacity. It keeps
+//! track of the logical length of a sized array, which the initializers otherwise return
+//! alongside the array. It provides the common vector operations and join, splice and resize
+//! equivalents which respect the logical length instead of padding with a `fill` value.
+//!
+//! ## Ring
+//!
+//! The [`RingBuffer`](crate::RingBuffer) type is a heapless first-in-first-out queue with a fixed
+//! capacity, which overwrites its oldest element when full. It is a sliding window that does not
+//! need to move all elements over like [`drift_to_begin`](crate::drift_to_begin) does, and can be
+//! linearized back into a sized array.
+//!
+//! ## String
+//!
+//! The [`ArrayString`](crate::ArrayString) type is a UTF-8 string with a fixed capacity, which
+//! supports [`write!`](::core::write) and dereferences to [`str`]. It converts from and to the
+//! NUL-terminated and space-padded `[u8; CAPACITY]` buffers in which fixed-width text fields are
+//! commonly stored.
+//!
+//! ## Bits
+//!
+//! The [`BitArray`](crate::BitArray) type is a fixed-size set of flags stored in a `[u32; WORDS]`
+//! array. It sets, clears, toggles, counts and iterates over bits, combines sets with the bitwise
+//! operators and converts from and to sized arrays of booleans.
+//!
+//! ## Bytes
+//!
+//! The [`ByteWriter`](crate::ByteWriter) type assembles protocol frames in a `[u8; SIZE]` buffer.
+//! It appends integers in either endianness, sized arrays and slices, and reports writes which do
+//! not fit as a [`SizeError`](crate::SizeError) instead of silently truncating them. Its
+//! counterpart, the [`ArrayReader`](crate::ArrayReader) type, parses such frames again by
+//! reading sized arrays, integers and nested length-prefixed fields, and reports frames which are
+//! too short as a [`ReadError`](crate::ReadError).
+//!
+//! ## Const contexts
+//!
+//! The [`drift_to_begin`](crate::drift_to_begin), [`drift_to_end`](crate::drift_to_end),
+//! [`array_resize`](crate::array_resize), [`superimpose`](crate::superimpose),
+//! [`join`](crate::join), [`splice`](crate::splice) and [`sized_slice`](crate::sized_slice)
+//! utilities are `const fn`s. This allows lookup tables and protocol headers to be composed at
+//! compile time and stored in `const` and `static` items.
+//!
+//! ## Grid
+//!
+//! Two-dimensional arrays, `[[T; COLUMNS]; ROWS]`, such as LED matrices or small images, are
+//! supported by [`initialize_2d`](crate::initialize_2d), [`transpose`](crate::transpose),
+//! [`extract_row`](crate::extract_row), [`extract_column`](crate::extract_column),
+//! [`sized_slice_2d`](crate::sized_slice_2d) and [`superimpose_2d`](crate::superimpose_2d).
+//! Positions are `(row, column)` pairs and follow the same truncation rules as the
+//! one-dimensional utilities. All of these except [`initialize_2d`](crate::initialize_2d) and
+//! [`transpose`](crate::transpose) are `const fn`s as well.
+//!
+//! Grids are converted from and to flat arrays with [`chunk`](crate::chunk) and
+//! [`flatten`](crate::flatten), for example to frame a byte buffer into fixed-size blocks. Their
+//! [`chunk_exact`](crate::chunk_exact) and [`flatten_exact`](crate::flatten_exact) variants verify
+//! at compile time that the sizes match.
+//!
+//! ## Methods
+//!
+//! The resize, join, splice, slice, superimpose and drift utilities, along with their `_with`,
+//! `_into`, `_move` and `_in_place` forms, are also available as methods through the
+//! [`ArrayExt`](crate::ArrayExt) trait, which is implemented for all sized arrays. This allows
+//! chaining operations instead of nesting function calls.
 //!
 //! # Usage
 //!
-//! Since we are using sized arrays, all utilities heavily rely on const generics. Furthermore, all
-//! functions are only implemented for types with the [`Copy`](::core::marker::Copy) trait. Some
-//! utilities, namely the functions without additional `fill` parameter, also depend on the
-//! [`Default`](::core::default::Default) trait.
+//! Since we are using sized arrays, all utilities heavily rely on const generics. Furthermore, most
+//! functions are only implemented for types with the [`Copy`](::core::marker::Copy) trait.
+//!
+//! For types which are not [`Copy`](::core::marker::Copy), every utility has a move-based variant
+//! (e.g. [`join_with`](crate::join_with) or [`superimpose_move`](crate::superimpose_move)). These
+//! consume the given arrays, move the elements into the result and drop the elements that are
+//! truncated. Instead of a `fill` value, they take a closure producing the `fill` elements, for
+//! which [`Default::default`](::core::default::Default::default) can be used.
+//!
+//! Passing large arrays by value copies them over the stack. For those cases the
+//! [`superimpose_in_place`](crate::superimpose_in_place),
+//! [`drift_to_begin_in_place`](crate::drift_to_begin_in_place),
+//! [`drift_to_end_in_place`](crate::drift_to_end_in_place) and
+//! [`array_resize_into`](crate::array_resize_into) utilities operate on borrowed arrays instead.
 //!
 //! Here are some examples or the usage of this crate.
 //!
@@ -79,10 +227,104 @@
 //! // filling in `42` for all new elements.
 //! assert_eq!(drift_to_end(array, 3, 0, 42), [42, 42, 42, 42, 1, 2, 3]);
 //! ```
+//!
+//! ## Chaining
+//!
+//! ```
+//! use array_utils::ArrayExt;
+//!
+//! // Join a header with a payload, and pad the result to the frame size.
+//! let frame: [u8; 8] = [0xAA, 0xBB].join::<3, 5>([1, 2, 3], 0).resize(0xFF);
+//! assert_eq!(frame, [0xAA, 0xBB, 1, 2, 3, 0xFF, 0xFF, 0xFF]);
+//! ```
 
 #![no_std]
 #![warn(missing_docs)]
 
+#[cfg(feature = "bytes")]
+pub mod array_reader;
+#[cfg(feature = "string")]
+pub mod array_string;
+#[cfg(feature = "vec")]
+pub mod array_vec;
+#[cfg(feature = "bits")]
+pub mod bit_array;
+#[cfg(feature = "bytes")]
+pub mod byte_writer;
+#[cfg(feature = "collect")]
+mod collect;
+mod ext;
+#[cfg(feature = "grid")]
+mod grid;
+#[cfg(feature = "insert")]
+mod insert;
+#[cfg(feature = "interleave")]
+mod interleave;
+#[cfg(feature = "map")]
+mod map;
+#[cfg(feature = "ring")]
+pub mod ring_buffer;
+#[cfg(feature = "rotate")]
+mod rotate;
+#[cfg(feature = "search")]
+mod search;
+#[cfg(feature = "sort")]
+mod sort;
+#[cfg(test)]
+pub(crate) mod test_util;
+
+#[cfg(feature = "bytes")]
+pub use array_reader::{ArrayReader, ReadError};
+#[cfg(feature = "string")]
+pub use array_string::ArrayString;
+#[cfg(feature = "vec")]
+pub use array_vec::ArrayVec;
+#[cfg(feature = "bits")]
+pub use bit_array::BitArray;
+#[cfg(feature = "bytes")]
+pub use byte_writer::ByteWriter;
+#[cfg(feature = "collect")]
+pub use collect::{
+    collect_array, collect_array_with, try_collect_array, CollectError, IteratorExt,
+};
+pub use ext::ArrayExt;
+#[cfg(feature = "grid")]
+pub use grid::{
+    chunk, chunk_exact, extract_column, extract_row, flatten, flatten_exact, initialize_2d,
+    sized_slice_2d, superimpose_2d, transpose,
+};
+#[cfg(feature = "insert")]
+pub use insert::{insert_at, insert_slice_at, remove_at, remove_range};
+#[cfg(feature = "interleave")]
+pub use interleave::{
+    deinterleave, deinterleave_exact, deinterleave_pair, interleave, interleave_exact,
+    interleave_pair,
+};
+#[cfg(feature = "map")]
+pub use map::{map_indexed, try_map, unzip, zip, zip_with};
+#[cfg(feature = "ring")]
+pub use ring_buffer::RingBuffer;
+#[cfg(feature = "rotate")]
+pub use rotate::{rotate_left, rotate_left_const, rotate_right, rotate_right_const};
+#[cfg(feature = "search")]
+pub use search::{
+    binary_search, binary_search_i16, binary_search_i32, binary_search_i64, binary_search_i8,
+    binary_search_isize, binary_search_u16, binary_search_u32, binary_search_u64, binary_search_u8,
+    binary_search_usize, count_occurrences, count_occurrences_i16, count_occurrences_i32,
+    count_occurrences_i64, count_occurrences_i8, count_occurrences_isize, count_occurrences_u16,
+    count_occurrences_u32, count_occurrences_u64, count_occurrences_u8, count_occurrences_usize,
+    find_subarray, find_subarray_i16, find_subarray_i32, find_subarray_i64, find_subarray_i8,
+    find_subarray_isize, find_subarray_u16, find_subarray_u32, find_subarray_u64, find_subarray_u8,
+    find_subarray_usize, position, rfind_subarray, rfind_subarray_i16, rfind_subarray_i32,
+    rfind_subarray_i64, rfind_subarray_i8, rfind_subarray_isize, rfind_subarray_u16,
+    rfind_subarray_u32, rfind_subarray_u64, rfind_subarray_u8, rfind_subarray_usize,
+};
+#[cfg(feature = "sort")]
+pub use sort::{
+    argsort, is_sorted, sorted, sorted_by_key, sorted_i16, sorted_i32, sorted_i64, sorted_i8,
+    sorted_isize, sorted_u16, sorted_u32, sorted_u64, sorted_u8, sorted_usize,
+};
+
 const fn min_of_sizes(x: usize, y: usize) -> usize {
     if x < y {
         x
@@ -91,10 +333,167 @@ const fn min_of_sizes(x: usize, y: usize) -> usize {
     }
 }
 
+/// Error returned by the checked (`try_`) utilities.
+///
+/// Describes what the permissive counterpart of the utility would have silently truncated or
+/// filled up with the `fill` value.
+#[cfg(any(
+    feature = "resize",
+    feature = "superimpose",
+    feature = "join",
+    feature = "splice",
+    feature = "slice",
+    feature = "bytes",
+    feature = "string"
+))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeError {
+    /// The given elements do not fit. `dropped` elements would have been truncated.
+    Truncated {
+        /// Amount of elements that would have been truncated
+        dropped: usize,
+    },
+    /// The given elements are too few. `padded` elements would have been filled up.
+    Padded {
+        /// Amount of elements that would have been filled up with the `fill` value
+        padded: usize,
+    },
+    /// The given `index` lies outside of an array with `size` elements.
+    OutOfRange {
+        /// The index that was given
+        index: usize,
+        /// The size of the array that was indexed
+        size: usize,
+    },
+    /// The given range `from..till` is reversed, as `from` lies after `till`.
+    ReversedRange {
+        /// The start of the range that was given
+        from: usize,
+        /// The end of the range that was given
+        till: usize,
+    },
+}
+
+#[cfg(any(
+    feature = "resize",
+    feature = "superimpose",
+    feature = "join",
+    feature = "splice",
+    feature = "slice",
+    feature = "bytes",
+    feature = "string"
+))]
+impl ::core::fmt::Display for SizeError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            SizeError::Truncated { dropped } => {
+                write!(f, "{} elements would be truncated", dropped)
+            }
+            SizeError::Padded { padded } => write!(f, "{} elements would be padded", padded),
+            SizeError::OutOfRange { index, size } => write!(
+                f,
+                "index {} is out of range for an array of size {}",
+                index, size
+            ),
+            SizeError::ReversedRange { from, till } => {
+                write!(f, "range {}..{} is reversed", from, till)
+            }
+        }
+    }
+}
+
+/// Error returned by [`try_initialize_from`](crate::try_initialize_from) when the generator
+/// closure fails.
+///
+/// Carries the error returned by the closure, the index at which it was returned and the array as
+/// far as it was initialized. All elements from `index` onwards hold fill values.
+#[cfg(feature = "initialize")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitializeError<T, E, const SIZE: usize> {
+    /// The error returned by the generator closure
+    pub error: E,
+    /// The index at which the generator closure returned the error
+    pub index: usize,
+    /// The partially initialized array, filled up after `index`
+    pub partial: [T; SIZE],
+}
+
+#[cfg(feature = "initialize")]
+impl<T, E, const SIZE: usize> ::core::fmt::Display for InitializeError<T, E, SIZE>
+where
+    E: ::core::fmt::Display,
+{
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(
+            f,
+            "initialization failed at index {}: {}",
+            self.index, self.error
+        )
+    }
+}
+
+/// Compile time assertion that the size of a whole array equals the sum of the sizes of its
+/// parts.
+///
+/// Evaluating [`VALID`](SumOfSizes::VALID) for a mismatching set of sizes fails the build of the
+/// monomorphized function that uses it.
+#[cfg(any(feature = "join", feature = "splice"))]
+struct SumOfSizes<const LEFT_SIZE: usize, const RIGHT_SIZE: usize, const WHOLE_SIZE: usize>;
+
+#[cfg(any(feature = "join", feature = "splice"))]
+impl<const LEFT_SIZE: usize, const RIGHT_SIZE: usize, const WHOLE_SIZE: usize>
+    SumOfSizes<LEFT_SIZE, RIGHT_SIZE, WHOLE_SIZE>
+{
+    const VALID: () = assert!(
+        LEFT_SIZE + RIGHT_SIZE == WHOLE_SIZE,
+        "the size of the whole array is not the sum of the sizes of its parts"
+    );
+}
+
+/// Compile time assertion that the size of a flat array equals the amount of elements in a grid.
+///
+/// Evaluating [`VALID`](ProductOfSizes::VALID) for a mismatching set of sizes fails the build of
+/// the monomorphized function that uses it.
+#[cfg(any(feature = "grid", feature = "interleave"))]
+struct ProductOfSizes<const ROWS: usize, const COLUMNS: usize, const FLAT_SIZE: usize>;
+
+#[cfg(any(feature = "grid", feature = "interleave"))]
+impl<const ROWS: usize, const COLUMNS: usize, const FLAT_SIZE: usize>
+    ProductOfSizes<ROWS, COLUMNS, FLAT_SIZE>
+{
+    const VALID: () = assert!(
+        ROWS * COLUMNS == FLAT_SIZE,
+        "the size of the flat array is not the amount of elements in the grid"
+    );
+}
+
+/// Compare the amount of available elements with the amount of requested elements.
+#[cfg(any(
+    feature = "resize",
+    feature = "superimpose",
+    feature = "join",
+    feature = "splice",
+    feature = "slice"
+))]
+const fn check_sizes(available: usize, requested: usize) -> Result<(), SizeError> {
+    if available > requested {
+        Err(SizeError::Truncated {
+            dropped: available - requested,
+        })
+    } else if available < requested {
+        Err(SizeError::Padded {
+            padded: requested - available,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 /// Initialize a sized array from a closure taking the index and outputting the elements.
 ///
 /// Generates a new sized array generated from generator closure, which turns a index into a
-/// element of the generated array.
+/// element of the generated array. The elements are constructed in place, so `T` needs to be
+/// neither [`Copy`](::core::marker::Copy) nor [`Default`](::core::default::Default).
 ///
 /// # Examples
 ///
@@ -111,22 +510,31 @@ const fn min_of_sizes(x: usize, y: usize) -> usize {
 /// }
 ///
 /// assert_eq!(initialize_from(get_prime), [2, 3, 5, 7, 9, 11]);
+///
+/// // The closure may carry state, such as a pseudo-random generator.
+/// let mut seed = 1u32;
+/// let noise: [u32; 4] = initialize_from(|_| {
+///     seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+///     seed >> 16
+/// });
+/// assert_eq!(noise, [16_838, 38_526, 10_113, 50_283]);
+///
+/// // Types without a default value work as well.
+/// use core::num::NonZeroU32;
+/// let ids: [NonZeroU32; 3] = initialize_from(|index| NonZeroU32::new(index as u32 + 1).unwrap());
+/// assert_eq!(ids.map(NonZeroU32::get), [1, 2, 3]);
 /// ```
 ///
 /// # Panics
 ///
-/// Only panics when the given closure `f` panics.
+/// Only panics when the given closure `f` panics. All elements that were already produced are
+/// dropped in that case.
 #[cfg(feature = "initialize")]
 pub fn initialize_from<T, F, const OUTPUT_SIZE: usize>(f: F) -> [T; OUTPUT_SIZE]
 where
-    T: Copy + Default,
-    F: Fn(usize) -> T,
+    F: FnMut(usize) -> T,
 {
-    let mut buffer = [T::default(); OUTPUT_SIZE];
-    for i in 0..OUTPUT_SIZE {
-        buffer[i] = f(i);
-    }
-    buffer
+    ::core::array::from_fn(f)
 }
 
 /// Initialize a sized array from a closure till a certain value appears.
@@ -149,15 +557,13 @@ where
 /// assert_eq!(till_five, ([0, 1, 2, 3, 4, 42, 42, 42], 5));
 ///
 /// // Especially useful for null terminated data streams.
-/// # static mut COUNTER: usize = 0;
-/// fn get_stream_byte() -> u8 {
-///     # unsafe {COUNTER += 1;
-///     # [0, 4, 2, 1, 3, 3, 7, 0][COUNTER]}
-///     // ...snip
-/// }
+/// let mut stream = [4, 2, 1, 3, 3, 7, 0, 9].iter().copied();
 ///
 /// // Fetch stream bytes till it find a `0` byte. Fill the rest with `0` bytes.
-/// assert_eq!(initialize_till(|_| get_stream_byte(), 0, 0), ([4, 2, 1, 3, 3, 7, 0, 0, 0], 6));
+/// assert_eq!(
+///     initialize_till(|_| stream.next().unwrap_or(0), 0, 0),
+///     ([4, 2, 1, 3, 3, 7, 0, 0, 0], 6)
+/// );
 /// ```
 ///
 /// # Panics
@@ -165,13 +571,13 @@ where
 /// Only panics if the given `f` panics.
 #[cfg(feature = "initialize")]
 pub fn initialize_till<T, F, const OUTPUT_SIZE: usize>(
-    f: F,
+    mut f: F,
     till: T,
     fill: T,
 ) -> ([T; OUTPUT_SIZE], usize)
 where
     T: Copy + PartialEq,
-    F: Fn(usize) -> T,
+    F: FnMut(usize) -> T,
 {
     let mut buffer = [fill; OUTPUT_SIZE];
     for i in 0..OUTPUT_SIZE {
@@ -185,6 +591,59 @@ where
     (buffer, OUTPUT_SIZE)
 }
 
+/// Initialize a sized array from a closure till a certain value appears, without requiring
+/// [`Copy`](::core::marker::Copy).
+///
+/// Works the same as [`initialize_till`](crate::initialize_till), but the elements after the
+/// `till` value are produced by calling the `fill` closure once per element. The `till` value
+/// itself is dropped. Passing [`Default::default`](::core::default::Default::default) as `fill`
+/// pads with default values.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::initialize_till_with;
+/// use core::cell::RefCell;
+///
+/// let (cells, length): ([RefCell<usize>; 4], usize) =
+///     initialize_till_with(|index| RefCell::new(index), RefCell::new(2), || RefCell::new(42));
+///
+/// assert_eq!(length, 2);
+/// assert_eq!(cells.map(RefCell::into_inner), [0, 1, 42, 42]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `f` or `fill` panics. All elements that were already produced are
+/// dropped in that case.
+#[cfg(feature = "initialize")]
+pub fn initialize_till_with<T, F, G, const OUTPUT_SIZE: usize>(
+    mut f: F,
+    till: T,
+    mut fill: G,
+) -> ([T; OUTPUT_SIZE], usize)
+where
+    T: PartialEq,
+    F: FnMut(usize) -> T,
+    G: FnMut() -> T,
+{
+    let mut length = OUTPUT_SIZE;
+    let buffer = ::core::array::from_fn(|i| {
+        if length != OUTPUT_SIZE {
+            return fill();
+        }
+
+        let value = f(i);
+        if value == till {
+            length = i;
+            return fill();
+        }
+
+        value
+    });
+    (buffer, length)
+}
+
 /// Initialize a sized array from a closure taking the index and outputting an
 /// [`Option`](::core::option::Option) of a element, stopping when the first
 /// [`None`](::core::option::Option) is encountered.
@@ -211,12 +670,12 @@ where
 /// Only panics if the given `f` panics.
 #[cfg(feature = "initialize")]
 pub fn initialize_from_option<T, F, const OUTPUT_SIZE: usize>(
-    f: F,
+    mut f: F,
     fill: T,
 ) -> ([T; OUTPUT_SIZE], usize)
 where
     T: Copy,
-    F: Fn(usize) -> Option<T>,
+    F: FnMut(usize) -> Option<T>,
 {
     let mut buffer = [fill; OUTPUT_SIZE];
     for i in 0..OUTPUT_SIZE {
@@ -228,6 +687,58 @@ where
     (buffer, OUTPUT_SIZE)
 }
 
+/// Initialize a sized array from a closure outputting an [`Option`](::core::option::Option) of
+/// a element, without requiring [`Copy`](::core::marker::Copy).
+///
+/// Works the same as [`initialize_from_option`](crate::initialize_from_option), but the elements
+/// after the first [`None`](::core::option::Option) are produced by calling the `fill` closure
+/// once per element.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::initialize_from_option_with;
+/// use core::cell::RefCell;
+///
+/// let (cells, length): ([RefCell<usize>; 4], usize) = initialize_from_option_with(
+///     |index| if index == 3 { None } else { Some(RefCell::new(index)) },
+///     Default::default,
+/// );
+///
+/// assert_eq!(length, 3);
+/// assert_eq!(cells.map(RefCell::into_inner), [0, 1, 2, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `f` or `fill` panics. All elements that were already produced are
+/// dropped in that case.
+#[cfg(feature = "initialize")]
+pub fn initialize_from_option_with<T, F, G, const OUTPUT_SIZE: usize>(
+    mut f: F,
+    mut fill: G,
+) -> ([T; OUTPUT_SIZE], usize)
+where
+    F: FnMut(usize) -> Option<T>,
+    G: FnMut() -> T,
+{
+    let mut length = OUTPUT_SIZE;
+    let buffer = ::core::array::from_fn(|i| {
+        if length != OUTPUT_SIZE {
+            return fill();
+        }
+
+        match f(i) {
+            None => {
+                length = i;
+                fill()
+            }
+            Some(value) => value,
+        }
+    });
+    (buffer, length)
+}
+
 /// Initialize a sized array from a closure taking the index and outputting an
 /// [`Result`](::core::result::Result) of a element, stopping when the first
 /// [`Err`](::core::result::Result) is encountered.
@@ -254,12 +765,12 @@ where
 /// Only panics if the given `f` panics.
 #[cfg(feature = "initialize")]
 pub fn initialize_from_result<T, F, E, const OUTPUT_SIZE: usize>(
-    f: F,
+    mut f: F,
     fill: T,
 ) -> ([T; OUTPUT_SIZE], usize)
 where
     T: Copy,
-    F: Fn(usize) -> Result<T, E>,
+    F: FnMut(usize) -> Result<T, E>,
 {
     let mut buffer = [fill; OUTPUT_SIZE];
     for i in 0..OUTPUT_SIZE {
@@ -271,173 +782,815 @@ where
     (buffer, OUTPUT_SIZE)
 }
 
-/// Create an array containing a slice of original array at the end of the array.
+/// Initialize a sized array from a closure outputting an [`Result`](::core::result::Result) of
+/// a element, without requiring [`Copy`](::core::marker::Copy).
 ///
-/// Floats a part of sized `array` with the range `..till` to the end of the result array
-/// with `margin` elements after the slice. All elements (including the margin) not filled with
-/// the slice will be filled with the `fill` value.
+/// Works the same as [`initialize_from_result`](crate::initialize_from_result), but the elements
+/// after the first [`Err`](::core::result::Result) are produced by calling the `fill` closure
+/// once per element.
 ///
 /// # Examples
 ///
 /// ```
-/// use array_utils::drift_to_end;
+/// use array_utils::initialize_from_result_with;
+/// use core::cell::RefCell;
 ///
-/// // Float the elements with indices `..3` to the end with a margin of `0` elements,
-/// // filling in `42` for all new elements.
-/// assert_eq!(drift_to_end([1, 2, 3, 0, 0, 0, 0], 3, 0, 42), [42, 42, 42, 42, 1, 2, 3]);
+/// let (cells, length): ([RefCell<usize>; 4], usize) = initialize_from_result_with(
+///     |index| if index == 3 { Err(()) } else { Ok(RefCell::new(index)) },
+///     Default::default,
+/// );
+///
+/// assert_eq!(length, 3);
+/// assert_eq!(cells.map(RefCell::into_inner), [0, 1, 2, 0]);
 /// ```
 ///
-/// # Notes
+/// # Panics
 ///
-/// * If `till` is equal to `0` the resulting buffer will be `[fill; SIZE]`.
-/// * If `margin` is greater or equal to `SIZE` the resulting buffer will be `[fill; SIZE]`.
-#[cfg(feature = "drift")]
-pub fn drift_to_end<T, const SIZE: usize>(
-    array: [T; SIZE],
-    till: usize,
-    margin: usize,
-    fill: T,
-) -> [T; SIZE]
+/// Only panics if the given `f` or `fill` panics. All elements that were already produced are
+/// dropped in that case.
+#[cfg(feature = "initialize")]
+pub fn initialize_from_result_with<T, F, G, E, const OUTPUT_SIZE: usize>(
+    mut f: F,
+    mut fill: G,
+) -> ([T; OUTPUT_SIZE], usize)
 where
-    T: Copy,
+    F: FnMut(usize) -> Result<T, E>,
+    G: FnMut() -> T,
 {
-    let mut buffer = [fill; SIZE];
-    for i in 0..till {
-        buffer[SIZE - margin - till + i] = array[i];
-    }
-    buffer
+    let mut length = OUTPUT_SIZE;
+    let buffer = ::core::array::from_fn(|i| {
+        if length != OUTPUT_SIZE {
+            return fill();
+        }
+
+        match f(i) {
+            Err(_) => {
+                length = i;
+                fill()
+            }
+            Ok(value) => value,
+        }
+    });
+    (buffer, length)
 }
 
-/// Create an array containing a slice of original array at the beginning of the array.
+/// Initialize a sized array from a closure outputting a [`Result`](::core::result::Result) of a
+/// element, returning the error when the first [`Err`](::core::result::Result) is encountered.
 ///
-/// Floats a part of sized `array` with the range `from..` to the beginning of the result array
-/// with `margin` elements before the slice. All elements (including the margin) not filled with
-/// the slice will be filled with the `fill` value.
+/// Works like [`initialize_from_result`](crate::initialize_from_result), but instead of throwing
+/// the error away it is returned inside of an [`InitializeError`](crate::InitializeError), along
+/// with the index where the initialization stopped and the partially initialized array. The
+/// elements from that index onwards are filled with the `fill` value. The closure is not called
+/// again after it returned an error.
 ///
 /// # Examples
 ///
 /// ```
-/// use array_utils::drift_to_begin;
+/// use array_utils::{try_initialize_from, InitializeError};
 ///
-/// // Float the elements with indices `0..` to the beginning with a margin of `1` elements,
-/// // filling in `0x00` for all new elements.
-/// assert_eq!(drift_to_begin([1, 2, 3, 0, 0, 0, 0], 0, 1, 0x00), [0, 1, 2, 3, 0, 0, 0]);
+/// #[derive(Debug, PartialEq)]
+/// enum ReadError {
+///     EndOfData,
+///     Checksum,
+/// }
+///
+/// let flash = [7, 3, 9];
+/// let read = |index: usize| flash.get(index).copied().ok_or(ReadError::EndOfData);
+///
+/// assert_eq!(try_initialize_from::<_, _, _, 3>(read, 0), Ok([7, 3, 9]));
+/// assert_eq!(
+///     try_initialize_from::<_, _, _, 5>(read, 0),
+///     Err(InitializeError {
+///         error: ReadError::EndOfData,
+///         index: 3,
+///         partial: [7, 3, 9, 0, 0],
+///     })
+/// );
+///
+/// let corrupted = |index: usize| if index == 1 { Err(ReadError::Checksum) } else { Ok(index) };
+/// let error = try_initialize_from::<_, _, _, 4>(corrupted, 0).unwrap_err();
+/// assert_eq!((error.error, error.index), (ReadError::Checksum, 1));
 /// ```
 ///
-/// # Notes
+/// # Panics
 ///
-/// * If `till` is equal to `0` the resulting buffer will be `[fill; SIZE]`.
-/// * If `margin` is greater or equal to `SIZE` the resulting buffer will be `[fill; SIZE]`.
-#[cfg(feature = "drift")]
-pub fn drift_to_begin<T, const SIZE: usize>(
-    array: [T; SIZE],
-    from: usize,
-    margin: usize,
+/// Only panics if the given `f` panics.
+#[cfg(feature = "initialize")]
+pub fn try_initialize_from<T, F, E, const OUTPUT_SIZE: usize>(
+    mut f: F,
     fill: T,
-) -> [T; SIZE]
+) -> Result<[T; OUTPUT_SIZE], InitializeError<T, E, OUTPUT_SIZE>>
 where
     T: Copy,
+    F: FnMut(usize) -> Result<T, E>,
 {
-    let mut buffer = [fill; SIZE];
-    for i in from..SIZE {
-        if margin + i - from >= SIZE {
-            break;
+    let mut buffer = [fill; OUTPUT_SIZE];
+    for i in 0..OUTPUT_SIZE {
+        match f(i) {
+            Err(error) => {
+                return Err(InitializeError {
+                    error,
+                    index: i,
+                    partial: buffer,
+                })
+            }
+            Ok(value) => buffer[i] = value,
         }
-
-        buffer[margin + i - from] = array[i];
     }
-    buffer
+    Ok(buffer)
 }
 
-/// Resize a sized array to a different size.
+/// Initialize a sized array from a closure outputting a [`Result`](::core::result::Result) of a
+/// element, returning the error and without requiring [`Copy`](::core::marker::Copy).
 ///
-/// Copy over the element from `array` into the resulting array. Truncating the original array or
-/// filling unfilled elements the `fill` value.
+/// Works the same as [`try_initialize_from`](crate::try_initialize_from), but the elements of the
+/// partial array after the error are produced by calling the `fill` closure once per element.
+/// `fill` is never called when the initialization succeeds.
 ///
 /// # Examples
 ///
 /// ```
-/// use array_utils::array_resize;
+/// use array_utils::try_initialize_from_with;
+/// use core::cell::RefCell;
 ///
-/// // Truncating unnecessary values
-/// assert_eq!(array_resize([1, 2, 3], 0), [1, 2]);
+/// let error = try_initialize_from_with::<_, _, _, _, 4>(
+///     |index| if index == 2 { Err("bad block") } else { Ok(RefCell::new(index)) },
+///     Default::default,
+/// )
+/// .unwrap_err();
 ///
-/// // Inserting the `fill` value
-/// assert_eq!(array_resize([1, 2, 3], 0), [1, 2, 3, 0]);
+/// assert_eq!((error.error, error.index), ("bad block", 2));
+/// assert_eq!(error.partial.map(RefCell::into_inner), [0, 1, 0, 0]);
 /// ```
-#[cfg(feature = "resize")]
-pub fn array_resize<T, const INPUT_SIZE: usize, const OUTPUT_SIZE: usize>(
-    array: [T; INPUT_SIZE],
-    fill: T,
-) -> [T; OUTPUT_SIZE]
+///
+/// # Panics
+///
+/// Only panics if the given `f` or `fill` panics. All elements that were already produced are
+/// dropped in that case.
+#[cfg(feature = "initialize")]
+pub fn try_initialize_from_with<T, F, G, E, const OUTPUT_SIZE: usize>(
+    mut f: F,
+    mut fill: G,
+) -> Result<[T; OUTPUT_SIZE], InitializeError<T, E, OUTPUT_SIZE>>
 where
-    T: Copy,
+    F: FnMut(usize) -> Result<T, E>,
+    G: FnMut() -> T,
 {
-    let mut buffer = [fill; OUTPUT_SIZE];
-    for i in 0..min_of_sizes(INPUT_SIZE, OUTPUT_SIZE) {
-        buffer[i] = array[i];
+    let mut failure = None;
+    let buffer = ::core::array::from_fn(|i| {
+        if failure.is_some() {
+            return fill();
+        }
+
+        match f(i) {
+            Err(error) => {
+                failure = Some((error, i));
+                fill()
+            }
+            Ok(value) => value,
+        }
+    });
+
+    match failure {
+        None => Ok(buffer),
+        Some((error, index)) => Err(InitializeError {
+            error,
+            index,
+            partial: buffer,
+        }),
     }
-    buffer
 }
 
-/// Superimpose an sized `sub_array` upon another `main_array` at index `starting_from`.
+/// Initialize a sized array by threading an accumulator through a closure.
 ///
-/// Create a copy of the `main_array` and insert all elements of `sub_array` into it,
-/// starting from the `starting_from` index. If the `sub_array` has more elements than fit in the
-/// `main_array` they are ignored.
+/// Generates a new sized array where each element is produced by calling `f` with a mutable
+/// reference to the accumulator, which starts as `init`, and the index of the element. This works
+/// like [`Iterator::scan`](::core::iter::Iterator::scan) and is useful for tables where every
+/// element depends on the ones before it.
 ///
 /// # Examples
 ///
 /// ```
-/// use array_utils::superimpose;
+/// use array_utils::initialize_scan;
+///
+/// // Fibonacci numbers
+/// let fibonacci: [u64; 8] = initialize_scan((0, 1), |(a, b), _| {
+///     let value = *a;
+///     *a = *b;
+///     *b += value;
+///     value
+/// });
+/// assert_eq!(fibonacci, [0, 1, 1, 2, 3, 5, 8, 13]);
+///
+/// // Offsets of consecutive fields
+/// let sizes = [4, 2, 8, 1];
+/// let offsets: [usize; 4] = initialize_scan(0, |offset, index| {
+///     let current = *offset;
+///     *offset += sizes[index];
+///     current
+/// });
+/// assert_eq!(offsets, [0, 4, 6, 14]);
+/// ```
 ///
-/// assert_eq!(
-///     superimpose([0; 8], [1, 3, 3, 7], 2),
-///     [0, 0, 1, 3, 3, 7, 0, 0]
-/// );
+/// # Panics
 ///
-/// // Elements that don't fit in the main array size are ignored.
-/// assert_eq!(
-///     superimpose([1, 2, 3], [4, 2], 2),
-///     [1, 2, 4]
-/// );
-/// ```
-#[cfg(feature = "superimpose")]
-pub fn superimpose<T, const MAIN_SIZE: usize, const SUB_SIZE: usize>(
-    mut main_array: [T; MAIN_SIZE],
-    sub_array: [T; SUB_SIZE],
-    starting_from: usize,
-) -> [T; MAIN_SIZE]
+/// Only panics if the given `f` panics. All elements that were already produced are dropped in
+/// that case.
+#[cfg(feature = "initialize")]
+pub fn initialize_scan<T, A, F, const OUTPUT_SIZE: usize>(init: A, mut f: F) -> [T; OUTPUT_SIZE]
 where
-    T: Copy,
+    F: FnMut(&mut A, usize) -> T,
 {
-    for i in starting_from..min_of_sizes(starting_from + SUB_SIZE, MAIN_SIZE) {
-        main_array[i] = sub_array[i - starting_from];
-    }
-    main_array
+    let mut accumulator = init;
+    ::core::array::from_fn(|i| f(&mut accumulator, i))
 }
 
-/// Join two sized arrays together into a new array.
+/// Create an array containing a slice of original array at the end of the array.
 ///
-/// Create a sized array which contain all the elements of `left` and `right` back to back. If
-/// there are any elements left to fill, they are filled up with the `fill` value. Any values of
-/// `left` or `right` that don't fit in the given buffer are ignored.
+/// Floats a part of sized `array` with the range `..till` to the end of the result array
+/// with `margin` elements after the slice. All elements (including the margin) not filled with
+/// the slice will be filled with the `fill` value.
 ///
 /// # Examples
 ///
 /// ```
-/// use array_utils::join;
+/// use array_utils::drift_to_end;
 ///
-/// assert_eq!(join([1, 2, 3], [4, 5, 6], 0), [1, 2, 3, 4, 5, 6]);
+/// // Float the elements with indices `..3` to the end with a margin of `0` elements,
+/// // filling in `42` for all new elements.
+/// assert_eq!(drift_to_end([1, 2, 3, 0, 0, 0, 0], 3, 0, 42), [42, 42, 42, 42, 1, 2, 3]);
 ///
-/// // Leftover elements are filled up
+/// // If the slice and the margin don't fit, the first elements of the slice are truncated.
+/// assert_eq!(drift_to_end([1, 2, 3, 4, 5], 4, 2, 0), [2, 3, 4, 0, 0]);
+/// ```
+///
+/// # Notes
+///
+/// * If `till` is equal to `0` the resulting buffer will be `[fill; SIZE]`.
+/// * If `till` is greater than `SIZE` it is treated as `SIZE`.
+/// * If `margin` is greater or equal to `SIZE` the resulting buffer will be `[fill; SIZE]`.
+/// * If the slice and the margin together do not fit in `SIZE`, the first elements of the slice
+///   are truncated.
+#[cfg(feature = "drift")]
+pub const fn drift_to_end<T, const SIZE: usize>(
+    array: [T; SIZE],
+    till: usize,
+    margin: usize,
+    fill: T,
+) -> [T; SIZE]
+where
+    T: Copy,
+{
+    let till = min_of_sizes(till, SIZE);
+    let end = SIZE.saturating_sub(margin);
+    let length = min_of_sizes(till, end);
+
+    let mut buffer = [fill; SIZE];
+    let mut i = 0;
+    while i < length {
+        buffer[end - length + i] = array[till - length + i];
+        i += 1;
+    }
+    buffer
+}
+
+/// Float a slice of an array to the end of that same array.
+///
+/// The in-place counterpart of [`drift_to_end`](crate::drift_to_end). Instead of creating a new
+/// array, the given `array` is overwritten with the result. This avoids copying the whole array
+/// over the stack, which matters for large arrays.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::drift_to_end_in_place;
+///
+/// let mut array = [1, 2, 3, 0, 0, 0, 0];
+/// drift_to_end_in_place(&mut array, 3, 0, 42);
+/// assert_eq!(array, [42, 42, 42, 42, 1, 2, 3]);
+/// ```
+///
+/// # Notes
+///
+/// The same bounds and truncating behavior as [`drift_to_end`](crate::drift_to_end) apply.
+#[cfg(feature = "drift")]
+pub fn drift_to_end_in_place<T, const SIZE: usize>(
+    array: &mut [T; SIZE],
+    till: usize,
+    margin: usize,
+    fill: T,
+) where
+    T: Copy,
+{
+    let till = min_of_sizes(till, SIZE);
+    let end = SIZE.saturating_sub(margin);
+    let length = min_of_sizes(till, end);
+
+    array.copy_within(till - length..till, end - length);
+    array[..end - length].fill(fill);
+    array[end..].fill(fill);
+}
+
+/// Create an array containing a slice of original array at the end of the array, without
+/// requiring [`Copy`](::core::marker::Copy).
+///
+/// Moves the elements of `array` with the range `..till` to the end of the result array with
+/// `margin` elements after the slice. All elements (including the margin) not filled with the
+/// slice are produced by calling the `fill` closure. Elements of `array` which are not moved into
+/// the result are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::drift_to_end_with;
+/// use core::cell::RefCell;
+///
+/// let array = [1, 2, 3, 0, 0, 0, 0].map(RefCell::new);
+/// let drifted = drift_to_end_with(array, 3, 0, || RefCell::new(42));
+/// assert_eq!(drifted.map(RefCell::into_inner), [42, 42, 42, 42, 1, 2, 3]);
+/// ```
+///
+/// # Notes
+///
+/// * If `till` is equal to `0` the resulting buffer will only contain `fill` values.
+/// * If `margin` is greater or equal to `SIZE` the resulting buffer will only contain `fill`
+///   values.
+/// * If the slice and the margin together do not fit in `SIZE`, the first elements of the slice
+///   are truncated and dropped.
+///
+/// # Panics
+///
+/// Only panics if the given `fill` panics. All elements that were already moved are dropped in
+/// that case.
+#[cfg(feature = "drift")]
+pub fn drift_to_end_with<T, F, const SIZE: usize>(
+    array: [T; SIZE],
+    till: usize,
+    margin: usize,
+    mut fill: F,
+) -> [T; SIZE]
+where
+    F: FnMut() -> T,
+{
+    let till = min_of_sizes(till, SIZE);
+    let end = SIZE.saturating_sub(margin);
+    let begin = end - min_of_sizes(till, end);
+
+    let mut elements = IntoIterator::into_iter(array)
+        .take(till)
+        .skip(till - (end - begin));
+    ::core::array::from_fn(|i| {
+        if i < begin || i >= end {
+            return fill();
+        }
+
+        elements.next().unwrap_or_else(&mut fill)
+    })
+}
+
+/// Create an array containing a slice of original array at the beginning of the array.
+///
+/// Floats a part of sized `array` with the range `from..` to the beginning of the result array
+/// with `margin` elements before the slice. All elements (including the margin) not filled with
+/// the slice will be filled with the `fill` value.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::drift_to_begin;
+///
+/// // Float the elements with indices `0..` to the beginning with a margin of `1` elements,
+/// // filling in `0x00` for all new elements.
+/// assert_eq!(drift_to_begin([1, 2, 3, 0, 0, 0, 0], 0, 1, 0x00), [0, 1, 2, 3, 0, 0, 0]);
+/// ```
+///
+/// # Notes
+///
+/// * If `from` is greater or equal to `SIZE` the resulting buffer will be `[fill; SIZE]`.
+/// * If `margin` is greater or equal to `SIZE` the resulting buffer will be `[fill; SIZE]`.
+/// * If the slice and the margin together do not fit in `SIZE`, the last elements of the slice
+///   are truncated.
+#[cfg(feature = "drift")]
+pub const fn drift_to_begin<T, const SIZE: usize>(
+    array: [T; SIZE],
+    from: usize,
+    margin: usize,
+    fill: T,
+) -> [T; SIZE]
+where
+    T: Copy,
+{
+    let mut buffer = [fill; SIZE];
+    let mut i = from;
+    while i < SIZE {
+        let target = margin.saturating_add(i - from);
+        if target >= SIZE {
+            break;
+        }
+
+        buffer[target] = array[i];
+        i += 1;
+    }
+    buffer
+}
+
+/// Float a slice of an array to the beginning of that same array.
+///
+/// The in-place counterpart of [`drift_to_begin`](crate::drift_to_begin). Instead of creating a
+/// new array, the given `array` is overwritten with the result. This avoids copying the whole
+/// array over the stack, which matters for large arrays.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::drift_to_begin_in_place;
+///
+/// let mut array = [1, 2, 3, 0, 0, 0, 0];
+/// drift_to_begin_in_place(&mut array, 0, 1, 0x00);
+/// assert_eq!(array, [0, 1, 2, 3, 0, 0, 0]);
+/// ```
+///
+/// # Notes
+///
+/// The same bounds and truncating behavior as [`drift_to_begin`](crate::drift_to_begin) apply.
+#[cfg(feature = "drift")]
+pub fn drift_to_begin_in_place<T, const SIZE: usize>(
+    array: &mut [T; SIZE],
+    from: usize,
+    margin: usize,
+    fill: T,
+) where
+    T: Copy,
+{
+    let from = min_of_sizes(from, SIZE);
+    let margin = min_of_sizes(margin, SIZE);
+    let length = min_of_sizes(SIZE - from, SIZE - margin);
+
+    array.copy_within(from..from + length, margin);
+    array[..margin].fill(fill);
+    array[margin + length..].fill(fill);
+}
+
+/// Create an array containing a slice of original array at the beginning of the array, without
+/// requiring [`Copy`](::core::marker::Copy).
+///
+/// Moves the elements of `array` with the range `from..` to the beginning of the result array
+/// with `margin` elements before the slice. All elements (including the margin) not filled with
+/// the slice are produced by calling the `fill` closure. Elements of `array` which are not moved
+/// into the result are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::drift_to_begin_with;
+/// use core::cell::RefCell;
+///
+/// let array = [1, 2, 3, 0, 0, 0, 0].map(RefCell::new);
+/// let drifted = drift_to_begin_with(array, 0, 1, || RefCell::new(0x00));
+/// assert_eq!(drifted.map(RefCell::into_inner), [0, 1, 2, 3, 0, 0, 0]);
+/// ```
+///
+/// # Notes
+///
+/// * If `from` is greater or equal to `SIZE` the resulting buffer will only contain `fill`
+///   values.
+/// * If `margin` is greater or equal to `SIZE` the resulting buffer will only contain `fill`
+///   values.
+/// * If the slice and the margin together do not fit in `SIZE`, the last elements of the slice
+///   are truncated and dropped.
+///
+/// # Panics
+///
+/// Only panics if the given `fill` panics. All elements that were already moved are dropped in
+/// that case.
+#[cfg(feature = "drift")]
+pub fn drift_to_begin_with<T, F, const SIZE: usize>(
+    array: [T; SIZE],
+    from: usize,
+    margin: usize,
+    mut fill: F,
+) -> [T; SIZE]
+where
+    F: FnMut() -> T,
+{
+    let mut elements = IntoIterator::into_iter(array).skip(from);
+    ::core::array::from_fn(|i| {
+        if i < margin {
+            return fill();
+        }
+
+        elements.next().unwrap_or_else(&mut fill)
+    })
+}
+
+/// Resize a sized array to a different size.
+///
+/// Copy over the element from `array` into the resulting array. Truncating the original array or
+/// filling unfilled elements the `fill` value.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::array_resize;
+///
+/// // Truncating unnecessary values
+/// assert_eq!(array_resize([1, 2, 3], 0), [1, 2]);
+///
+/// // Inserting the `fill` value
+/// assert_eq!(array_resize([1, 2, 3], 0), [1, 2, 3, 0]);
+/// ```
+#[cfg(feature = "resize")]
+pub const fn array_resize<T, const INPUT_SIZE: usize, const OUTPUT_SIZE: usize>(
+    array: [T; INPUT_SIZE],
+    fill: T,
+) -> [T; OUTPUT_SIZE]
+where
+    T: Copy,
+{
+    let mut buffer = [fill; OUTPUT_SIZE];
+    let mut i = 0;
+    while i < min_of_sizes(INPUT_SIZE, OUTPUT_SIZE) {
+        buffer[i] = array[i];
+        i += 1;
+    }
+    buffer
+}
+
+/// Resize a sized array into an existing array of a different size.
+///
+/// The in-place counterpart of [`array_resize`](crate::array_resize). Instead of creating a new
+/// array, the elements of `array` are copied into `output`, filling the unfilled elements with
+/// the `fill` value. This avoids copying the whole array over the stack, which matters for large
+/// arrays.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::array_resize_into;
+///
+/// let mut output = [9; 4];
+/// array_resize_into(&[1, 2], &mut output, 0);
+/// assert_eq!(output, [1, 2, 0, 0]);
+///
+/// array_resize_into(&[1, 2, 3, 4, 5, 6], &mut output, 0);
+/// assert_eq!(output, [1, 2, 3, 4]);
+/// ```
+#[cfg(feature = "resize")]
+pub fn array_resize_into<T, const INPUT_SIZE: usize, const OUTPUT_SIZE: usize>(
+    array: &[T; INPUT_SIZE],
+    output: &mut [T; OUTPUT_SIZE],
+    fill: T,
+) where
+    T: Copy,
+{
+    let length = min_of_sizes(INPUT_SIZE, OUTPUT_SIZE);
+
+    output[..length].copy_from_slice(&array[..length]);
+    output[length..].fill(fill);
+}
+
+/// Resize a sized array to a different size, reporting any truncating or filling.
+///
+/// The checked counterpart of [`array_resize`](crate::array_resize). Instead of truncating the
+/// original array or filling up the unfilled elements, a [`SizeError`](crate::SizeError) is
+/// returned describing how many elements would have been truncated or filled up.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::{try_array_resize, SizeError};
+///
+/// let resized: Result<[u8; 3], _> = try_array_resize([1, 2, 3]);
+/// assert_eq!(resized, Ok([1, 2, 3]));
+///
+/// let resized: Result<[u8; 2], _> = try_array_resize([1, 2, 3]);
+/// assert_eq!(resized, Err(SizeError::Truncated { dropped: 1 }));
+///
+/// let resized: Result<[u8; 5], _> = try_array_resize([1, 2, 3]);
+/// assert_eq!(resized, Err(SizeError::Padded { padded: 2 }));
+/// ```
+#[cfg(feature = "resize")]
+pub fn try_array_resize<T, const INPUT_SIZE: usize, const OUTPUT_SIZE: usize>(
+    array: [T; INPUT_SIZE],
+) -> Result<[T; OUTPUT_SIZE], SizeError>
+where
+    T: Copy,
+{
+    check_sizes(INPUT_SIZE, OUTPUT_SIZE)?;
+    Ok(::core::array::from_fn(|i| array[i]))
+}
+
+/// Resize a sized array to a different size, without requiring [`Copy`](::core::marker::Copy).
+///
+/// Moves the elements from `array` into the resulting array. Truncated elements are dropped and
+/// unfilled elements are produced by calling the `fill` closure.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::array_resize_with;
+/// use core::cell::RefCell;
+///
+/// let array = [1, 2, 3].map(RefCell::new);
+/// let resized: [RefCell<i32>; 5] = array_resize_with(array, Default::default);
+/// assert_eq!(resized.map(RefCell::into_inner), [1, 2, 3, 0, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `fill` panics. All elements that were already moved are dropped in
+/// that case.
+#[cfg(feature = "resize")]
+pub fn array_resize_with<T, F, const INPUT_SIZE: usize, const OUTPUT_SIZE: usize>(
+    array: [T; INPUT_SIZE],
+    mut fill: F,
+) -> [T; OUTPUT_SIZE]
+where
+    F: FnMut() -> T,
+{
+    let mut elements = IntoIterator::into_iter(array);
+    ::core::array::from_fn(|_| elements.next().unwrap_or_else(&mut fill))
+}
+
+/// Superimpose an sized `sub_array` upon another `main_array` at index `starting_from`.
+///
+/// Create a copy of the `main_array` and insert all elements of `sub_array` into it,
+/// starting from the `starting_from` index. If the `sub_array` has more elements than fit in the
+/// `main_array` they are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::superimpose;
+///
+/// assert_eq!(
+///     superimpose([0; 8], [1, 3, 3, 7], 2),
+///     [0, 0, 1, 3, 3, 7, 0, 0]
+/// );
+///
+/// // Elements that don't fit in the main array size are ignored.
+/// assert_eq!(
+///     superimpose([1, 2, 3], [4, 2], 2),
+///     [1, 2, 4]
+/// );
+/// ```
+#[cfg(feature = "superimpose")]
+pub const fn superimpose<T, const MAIN_SIZE: usize, const SUB_SIZE: usize>(
+    mut main_array: [T; MAIN_SIZE],
+    sub_array: [T; SUB_SIZE],
+    starting_from: usize,
+) -> [T; MAIN_SIZE]
+where
+    T: Copy,
+{
+    let mut i = starting_from;
+    while i < min_of_sizes(starting_from.saturating_add(SUB_SIZE), MAIN_SIZE) {
+        main_array[i] = sub_array[i - starting_from];
+        i += 1;
+    }
+    main_array
+}
+
+/// Superimpose an sized `sub_array` upon another `main_array` at index `starting_from`, in place.
+///
+/// The in-place counterpart of [`superimpose`](crate::superimpose). Instead of creating a copy of
+/// the `main_array`, the elements of `sub_array` are copied directly into it. This avoids copying
+/// the whole array over the stack, which matters for large arrays.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::superimpose_in_place;
+///
+/// let mut main_array = [0; 8];
+/// superimpose_in_place(&mut main_array, &[1, 3, 3, 7], 2);
+/// assert_eq!(main_array, [0, 0, 1, 3, 3, 7, 0, 0]);
+///
+/// // Elements that don't fit in the main array size are ignored.
+/// superimpose_in_place(&mut main_array, &[4, 2], 7);
+/// assert_eq!(main_array, [0, 0, 1, 3, 3, 7, 0, 4]);
+/// ```
+#[cfg(feature = "superimpose")]
+pub fn superimpose_in_place<T, const MAIN_SIZE: usize, const SUB_SIZE: usize>(
+    main_array: &mut [T; MAIN_SIZE],
+    sub_array: &[T; SUB_SIZE],
+    starting_from: usize,
+) where
+    T: Copy,
+{
+    let starting_from = min_of_sizes(starting_from, MAIN_SIZE);
+    let length = min_of_sizes(SUB_SIZE, MAIN_SIZE - starting_from);
+
+    main_array[starting_from..starting_from + length].copy_from_slice(&sub_array[..length]);
+}
+
+/// Superimpose an sized `sub_array` upon another `main_array` at index `starting_from`,
+/// reporting any truncating.
+///
+/// The checked counterpart of [`superimpose`](crate::superimpose). Instead of ignoring the
+/// elements of `sub_array` which don't fit in the `main_array`, a
+/// [`SizeError`](crate::SizeError) is returned.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::{try_superimpose, SizeError};
+///
+/// assert_eq!(try_superimpose([0; 4], [4, 2], 2), Ok([0, 0, 4, 2]));
+/// assert_eq!(try_superimpose([0; 4], [4, 2], 3), Err(SizeError::Truncated { dropped: 1 }));
+/// assert_eq!(
+///     try_superimpose([0; 4], [4, 2], 5),
+///     Err(SizeError::OutOfRange { index: 5, size: 4 })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// * [`SizeError::OutOfRange`](crate::SizeError::OutOfRange) if `starting_from` is greater than
+///   `MAIN_SIZE`.
+/// * [`SizeError::Truncated`](crate::SizeError::Truncated) if not all elements of `sub_array`
+///   fit in `main_array`.
+#[cfg(feature = "superimpose")]
+pub const fn try_superimpose<T, const MAIN_SIZE: usize, const SUB_SIZE: usize>(
+    main_array: [T; MAIN_SIZE],
+    sub_array: [T; SUB_SIZE],
+    starting_from: usize,
+) -> Result<[T; MAIN_SIZE], SizeError>
+where
+    T: Copy,
+{
+    if starting_from > MAIN_SIZE {
+        return Err(SizeError::OutOfRange {
+            index: starting_from,
+            size: MAIN_SIZE,
+        });
+    }
+
+    if SUB_SIZE > MAIN_SIZE - starting_from {
+        return Err(SizeError::Truncated {
+            dropped: SUB_SIZE - (MAIN_SIZE - starting_from),
+        });
+    }
+
+    Ok(superimpose(main_array, sub_array, starting_from))
+}
+
+/// Superimpose an sized `sub_array` upon another `main_array` at index `starting_from`, without
+/// requiring [`Copy`](::core::marker::Copy).
+///
+/// Moves all elements of `sub_array` into `main_array`, starting from the `starting_from` index.
+/// The elements of `main_array` which are overwritten and the elements of `sub_array` which don't
+/// fit in the `main_array` are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::superimpose_move;
+/// use core::cell::RefCell;
+///
+/// let main_array = [1, 2, 3].map(RefCell::new);
+/// let sub_array = [4, 2].map(RefCell::new);
+/// assert_eq!(superimpose_move(main_array, sub_array, 2).map(RefCell::into_inner), [1, 2, 4]);
+/// ```
+#[cfg(feature = "superimpose")]
+pub fn superimpose_move<T, const MAIN_SIZE: usize, const SUB_SIZE: usize>(
+    mut main_array: [T; MAIN_SIZE],
+    sub_array: [T; SUB_SIZE],
+    starting_from: usize,
+) -> [T; MAIN_SIZE] {
+    let targets = main_array.iter_mut().skip(starting_from);
+    for (target, element) in targets.zip(IntoIterator::into_iter(sub_array)) {
+        *target = element;
+    }
+    main_array
+}
+
+/// Join two sized arrays together into a new array.
+///
+/// Create a sized array which contain all the elements of `left` and `right` back to back. If
+/// there are any elements left to fill, they are filled up with the `fill` value. Any values of
+/// `left` or `right` that don't fit in the given buffer are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::join;
+///
+/// assert_eq!(join([1, 2, 3], [4, 5, 6], 0), [1, 2, 3, 4, 5, 6]);
+///
+/// // Leftover elements are filled up
 /// assert_eq!(join([1, 2, 3], [4, 5, 6], 0), [1, 2, 3, 4, 5, 6, 0, 0]);
 ///
 /// // The input arrays are truncated if the resulting array is too short.
 /// assert_eq!(join([1, 2, 3], [4, 5, 6], 0), [1, 2, 3, 4, 5]);
+///
+/// // Arrays can also be joined at compile time.
+/// const MAGIC: [u8; 4] = [0x7F, b'E', b'L', b'F'];
+/// const HEADER: [u8; 8] = join(MAGIC, [0x02, 0x01], 0);
+/// assert_eq!(HEADER, [0x7F, b'E', b'L', b'F', 0x02, 0x01, 0x00, 0x00]);
 /// ```
 #[cfg(feature = "join")]
-pub fn join<T, const LEFT_SIZE: usize, const RIGHT_SIZE: usize, const RESULT_SIZE: usize>(
+pub const fn join<T, const LEFT_SIZE: usize, const RIGHT_SIZE: usize, const RESULT_SIZE: usize>(
     left: [T; LEFT_SIZE],
     right: [T; RIGHT_SIZE],
     fill: T,
@@ -447,62 +1600,295 @@ where
 {
     let mut buffer = [fill; RESULT_SIZE];
 
-    for i in 0..min_of_sizes(LEFT_SIZE, RESULT_SIZE) {
+    let mut i = 0;
+    while i < min_of_sizes(LEFT_SIZE, RESULT_SIZE) {
         buffer[i] = left[i];
+        i += 1;
     }
 
-    for i in LEFT_SIZE..min_of_sizes(LEFT_SIZE + RIGHT_SIZE, RESULT_SIZE) {
-        if i - LEFT_SIZE >= RIGHT_SIZE {
-            break;
-        }
-
+    let mut i = LEFT_SIZE;
+    while i < min_of_sizes(LEFT_SIZE + RIGHT_SIZE, RESULT_SIZE) {
         buffer[i] = right[i - LEFT_SIZE];
+        i += 1;
     }
 
     buffer
 }
 
-/// Splice a sized arrays together into a two arrays.
+/// Join two sized arrays together into a new array, reporting any truncating or filling.
+///
+/// The checked counterpart of [`join`](crate::join). Instead of truncating `left` and `right` or
+/// filling up the leftover elements, a [`SizeError`](crate::SizeError) is returned describing how
+/// many elements would have been truncated or filled up.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::{try_join, SizeError};
+///
+/// let joined: Result<[u8; 6], _> = try_join([1, 2, 3], [4, 5, 6]);
+/// assert_eq!(joined, Ok([1, 2, 3, 4, 5, 6]));
+///
+/// let joined: Result<[u8; 5], _> = try_join([1, 2, 3], [4, 5, 6]);
+/// assert_eq!(joined, Err(SizeError::Truncated { dropped: 1 }));
+/// ```
+#[cfg(feature = "join")]
+pub fn try_join<T, const LEFT_SIZE: usize, const RIGHT_SIZE: usize, const RESULT_SIZE: usize>(
+    left: [T; LEFT_SIZE],
+    right: [T; RIGHT_SIZE],
+) -> Result<[T; RESULT_SIZE], SizeError>
+where
+    T: Copy,
+{
+    check_sizes(LEFT_SIZE + RIGHT_SIZE, RESULT_SIZE)?;
+    Ok(::core::array::from_fn(|i| {
+        if i < LEFT_SIZE {
+            left[i]
+        } else {
+            right[i - LEFT_SIZE]
+        }
+    }))
+}
+
+/// Join two sized arrays together into a new array of exactly the combined size.
+///
+/// The strict counterpart of [`join`](crate::join). It is verified at compile time that
+/// `RESULT_SIZE` is equal to `LEFT_SIZE + RIGHT_SIZE`, so no elements are ever truncated or
+/// filled up.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::join_exact;
+///
+/// assert_eq!(join_exact([1, 2, 3], [4, 5]), [1, 2, 3, 4, 5]);
+/// ```
+///
+/// A mismatch between the sizes is a build error.
+///
+/// ```compile_fail
+/// use array_utils::join_exact;
+///
+/// let joined: [u8; 6] = join_exact([1, 2, 3], [4, 5]);
+/// ```
+#[cfg(feature = "join")]
+pub fn join_exact<T, const LEFT_SIZE: usize, const RIGHT_SIZE: usize, const RESULT_SIZE: usize>(
+    left: [T; LEFT_SIZE],
+    right: [T; RIGHT_SIZE],
+) -> [T; RESULT_SIZE]
+where
+    T: Copy,
+{
+    let () = SumOfSizes::<LEFT_SIZE, RIGHT_SIZE, RESULT_SIZE>::VALID;
+
+    ::core::array::from_fn(|i| {
+        if i < LEFT_SIZE {
+            left[i]
+        } else {
+            right[i - LEFT_SIZE]
+        }
+    })
+}
+
+/// Join two sized arrays together into a new array, without requiring
+/// [`Copy`](::core::marker::Copy).
+///
+/// Moves all the elements of `left` and `right` back to back into a new sized array. If there
+/// are any elements left to fill, they are produced by calling the `fill` closure. Any values of
+/// `left` or `right` that don't fit in the given buffer are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::join_with;
+/// use core::cell::RefCell;
+///
+/// let left = [1, 2, 3].map(RefCell::new);
+/// let right = [4, 5, 6].map(RefCell::new);
+/// let joined: [RefCell<i32>; 8] = join_with(left, right, Default::default);
+/// assert_eq!(joined.map(RefCell::into_inner), [1, 2, 3, 4, 5, 6, 0, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `fill` panics. All elements that were already moved are dropped in
+/// that case.
+#[cfg(feature = "join")]
+pub fn join_with<T, F, const LEFT_SIZE: usize, const RIGHT_SIZE: usize, const RESULT_SIZE: usize>(
+    left: [T; LEFT_SIZE],
+    right: [T; RIGHT_SIZE],
+    mut fill: F,
+) -> [T; RESULT_SIZE]
+where
+    F: FnMut() -> T,
+{
+    let mut elements = IntoIterator::into_iter(left).chain(IntoIterator::into_iter(right));
+    ::core::array::from_fn(|_| elements.next().unwrap_or_else(&mut fill))
+}
+
+/// Splice a sized arrays together into a two arrays.
+///
+/// Create two arrays the left being filled up first, then the right. If the given `original`
+/// array is two small to fill both buffers, the `fill` value is used for the remaining elements.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::splice;
+///
+/// assert_eq!(splice([1, 2, 3, 4, 5, 6], 0), ([1, 2, 3], [4, 5, 6]));
+///
+/// // Leftover elements are not used
+/// assert_eq!(splice([1, 2, 3, 4, 5, 6, 0, 0], 0), ([1, 2, 3], [4, 5, 6]));
+///
+/// // If the `original` buffer is to small the remaining elements are filled in.
+/// assert_eq!(splice([1, 2, 3, 4, 5], 0), ([1, 2, 3], [4, 5, 0]));
+/// ```
+#[cfg(feature = "splice")]
+pub const fn splice<
+    T,
+    const ORIGINAL_SIZE: usize,
+    const LEFT_SIZE: usize,
+    const RIGHT_SIZE: usize,
+>(
+    original: [T; ORIGINAL_SIZE],
+    fill: T,
+) -> ([T; LEFT_SIZE], [T; RIGHT_SIZE])
+where
+    T: Copy,
+{
+    let mut left = [fill; LEFT_SIZE];
+    let mut right = [fill; RIGHT_SIZE];
+
+    let mut i = 0;
+    while i < min_of_sizes(LEFT_SIZE, ORIGINAL_SIZE) {
+        left[i] = original[i];
+        i += 1;
+    }
+
+    let mut i = LEFT_SIZE;
+    while i < min_of_sizes(LEFT_SIZE + RIGHT_SIZE, ORIGINAL_SIZE) {
+        right[i - LEFT_SIZE] = original[i];
+        i += 1;
+    }
+
+    (left, right)
+}
+
+/// Splice a sized arrays together into a two arrays, reporting any truncating or filling.
+///
+/// The checked counterpart of [`splice`](crate::splice). Instead of ignoring the leftover
+/// elements of `original` or filling up the remaining elements, a
+/// [`SizeError`](crate::SizeError) is returned describing how many elements would have been
+/// truncated or filled up.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::{try_splice, SizeError};
+///
+/// let spliced: Result<([u8; 3], [u8; 3]), _> = try_splice([1, 2, 3, 4, 5, 6]);
+/// assert_eq!(spliced, Ok(([1, 2, 3], [4, 5, 6])));
+///
+/// let spliced: Result<([u8; 3], [u8; 3]), _> = try_splice([1, 2, 3, 4, 5]);
+/// assert_eq!(spliced, Err(SizeError::Padded { padded: 1 }));
+/// ```
+#[cfg(feature = "splice")]
+pub fn try_splice<T, const ORIGINAL_SIZE: usize, const LEFT_SIZE: usize, const RIGHT_SIZE: usize>(
+    original: [T; ORIGINAL_SIZE],
+) -> Result<([T; LEFT_SIZE], [T; RIGHT_SIZE]), SizeError>
+where
+    T: Copy,
+{
+    check_sizes(ORIGINAL_SIZE, LEFT_SIZE + RIGHT_SIZE)?;
+    Ok((
+        ::core::array::from_fn(|i| original[i]),
+        ::core::array::from_fn(|i| original[LEFT_SIZE + i]),
+    ))
+}
+
+/// Splice a sized arrays into two arrays of exactly the original size together.
 ///
-/// Create two arrays the left being filled up first, then the right. If the given `original`
-/// array is two small to fill both buffers, the `fill` value is used for the remaining elements.
+/// The strict counterpart of [`splice`](crate::splice). It is verified at compile time that
+/// `ORIGINAL_SIZE` is equal to `LEFT_SIZE + RIGHT_SIZE`, so no elements are ever truncated or
+/// filled up.
 ///
 /// # Examples
 ///
 /// ```
-/// use array_utils::splice;
+/// use array_utils::splice_exact;
 ///
-/// assert_eq!(splice([1, 2, 3, 4, 5, 6], 0), ([1, 2, 3], [4, 5, 6]));
+/// assert_eq!(splice_exact([1, 2, 3, 4, 5]), ([1, 2, 3], [4, 5]));
+/// ```
 ///
-/// // Leftover elements are not used
-/// assert_eq!(splice([1, 2, 3, 4, 5, 6, 0, 0], 0), ([1, 2, 3], [4, 5, 6]));
+/// A mismatch between the sizes is a build error.
 ///
-/// // If the `original` buffer is to small the remaining elements are filled in.
-/// assert_eq!(splice([1, 2, 3, 4, 5], 0), ([1, 2, 3], [4, 5, 0]));
+/// ```compile_fail
+/// use array_utils::splice_exact;
+///
+/// let spliced: ([u8; 3], [u8; 3]) = splice_exact([1, 2, 3, 4, 5]);
 /// ```
 #[cfg(feature = "splice")]
-pub fn splice<T, const ORIGINAL_SIZE: usize, const LEFT_SIZE: usize, const RIGHT_SIZE: usize>(
+pub fn splice_exact<
+    T,
+    const ORIGINAL_SIZE: usize,
+    const LEFT_SIZE: usize,
+    const RIGHT_SIZE: usize,
+>(
     original: [T; ORIGINAL_SIZE],
-    fill: T,
 ) -> ([T; LEFT_SIZE], [T; RIGHT_SIZE])
 where
     T: Copy,
 {
-    let mut left = [fill; LEFT_SIZE];
-    let mut right = [fill; RIGHT_SIZE];
-
-    for i in 0..min_of_sizes(LEFT_SIZE, ORIGINAL_SIZE) {
-        left[i] = original[i];
-    }
+    let () = SumOfSizes::<LEFT_SIZE, RIGHT_SIZE, ORIGINAL_SIZE>::VALID;
 
-    for i in LEFT_SIZE..min_of_sizes(LEFT_SIZE + RIGHT_SIZE, ORIGINAL_SIZE) {
-        if i - LEFT_SIZE >= RIGHT_SIZE {
-            break;
-        }
-
-        right[i - LEFT_SIZE] = original[i];
-    }
+    (
+        ::core::array::from_fn(|i| original[i]),
+        ::core::array::from_fn(|i| original[LEFT_SIZE + i]),
+    )
+}
 
+/// Splice a sized arrays together into a two arrays, without requiring
+/// [`Copy`](::core::marker::Copy).
+///
+/// Moves the elements of `original` into two arrays, the left being filled up first, then the
+/// right. If the given `original` array is two small to fill both buffers, the remaining elements
+/// are produced by calling the `fill` closure. Leftover elements of `original` are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::splice_with;
+/// use core::cell::RefCell;
+///
+/// let original = [1, 2, 3, 4, 5].map(RefCell::new);
+/// let (left, right): ([RefCell<i32>; 3], [RefCell<i32>; 3]) =
+///     splice_with(original, Default::default);
+/// assert_eq!(left.map(RefCell::into_inner), [1, 2, 3]);
+/// assert_eq!(right.map(RefCell::into_inner), [4, 5, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `fill` panics. All elements that were already moved are dropped in
+/// that case.
+#[cfg(feature = "splice")]
+pub fn splice_with<
+    T,
+    F,
+    const ORIGINAL_SIZE: usize,
+    const LEFT_SIZE: usize,
+    const RIGHT_SIZE: usize,
+>(
+    original: [T; ORIGINAL_SIZE],
+    mut fill: F,
+) -> ([T; LEFT_SIZE], [T; RIGHT_SIZE])
+where
+    F: FnMut() -> T,
+{
+    let mut elements = IntoIterator::into_iter(original);
+    let left = ::core::array::from_fn(|_| elements.next().unwrap_or_else(&mut fill));
+    let right = ::core::array::from_fn(|_| elements.next().unwrap_or_else(&mut fill));
     (left, right)
 }
 
@@ -520,7 +1906,7 @@ where
 /// assert_eq!(sized_slice([1, 2, 3, 4, 5, 6, 7, 8, 9], 6, 8, 0), [7, 8, 0, 0, 0, 0]);
 /// ```
 #[cfg(feature = "slice")]
-pub fn sized_slice<T, const ORIGINAL_SIZE: usize, const SLICE_SIZE: usize>(
+pub const fn sized_slice<T, const ORIGINAL_SIZE: usize, const SLICE_SIZE: usize>(
     original: [T; ORIGINAL_SIZE],
     from: usize,
     till: usize,
@@ -531,19 +1917,116 @@ where
 {
     let mut buffer = [fill; SLICE_SIZE];
 
-    for i in from..min_of_sizes(till, ORIGINAL_SIZE) {
+    let mut i = from;
+    while i < min_of_sizes(till, ORIGINAL_SIZE) {
         if i - from >= SLICE_SIZE {
             break;
         }
 
         buffer[i - from] = original[i];
+        i += 1;
     }
     buffer
 }
 
+/// Create a sized slice of an array, reporting any truncating or filling.
+///
+/// The checked counterpart of [`sized_slice`](crate::sized_slice). Instead of truncating the
+/// range `from..till` or filling up the elements which are not contained in the `original`
+/// array, a [`SizeError`](crate::SizeError) is returned.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::{try_sized_slice, SizeError};
+///
+/// let slice: Result<[u8; 4], _> = try_sized_slice([1, 2, 3, 4, 5, 6, 7, 8, 9], 2, 6);
+/// assert_eq!(slice, Ok([3, 4, 5, 6]));
+///
+/// let slice: Result<[u8; 4], _> = try_sized_slice([1, 2, 3, 4, 5, 6, 7, 8, 9], 2, 8);
+/// assert_eq!(slice, Err(SizeError::Truncated { dropped: 2 }));
+///
+/// let slice: Result<[u8; 4], _> = try_sized_slice([1, 2, 3, 4, 5, 6, 7, 8, 9], 6, 12);
+/// assert_eq!(slice, Err(SizeError::OutOfRange { index: 12, size: 9 }));
+/// ```
+///
+/// # Errors
+///
+/// * [`SizeError::OutOfRange`](crate::SizeError::OutOfRange) if `till` is greater than
+///   `ORIGINAL_SIZE`.
+/// * [`SizeError::Truncated`](crate::SizeError::Truncated) if the range `from..till` contains
+///   more than `SLICE_SIZE` elements.
+/// * [`SizeError::ReversedRange`](crate::SizeError::ReversedRange) if `from` is greater than
+///   `till`.
+/// * [`SizeError::Padded`](crate::SizeError::Padded) if the range `from..till` contains less
+///   than `SLICE_SIZE` elements.
+#[cfg(feature = "slice")]
+pub fn try_sized_slice<T, const ORIGINAL_SIZE: usize, const SLICE_SIZE: usize>(
+    original: [T; ORIGINAL_SIZE],
+    from: usize,
+    till: usize,
+) -> Result<[T; SLICE_SIZE], SizeError>
+where
+    T: Copy,
+{
+    if till > ORIGINAL_SIZE {
+        return Err(SizeError::OutOfRange {
+            index: till,
+            size: ORIGINAL_SIZE,
+        });
+    }
+    if from > till {
+        return Err(SizeError::ReversedRange { from, till });
+    }
+
+    check_sizes(till - from, SLICE_SIZE)?;
+    Ok(::core::array::from_fn(|i| original[from + i]))
+}
+
+/// Create a sized slice of an array, without requiring [`Copy`](::core::marker::Copy).
+///
+/// Moves a part of sized array `original` from the index `from` till the index `till` into a new
+/// array. The elements which are not contained in the `original` array are produced by calling
+/// the `fill` closure. All other elements of `original` are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::sized_slice_with;
+/// use core::cell::RefCell;
+///
+/// let original = [1, 2, 3, 4, 5, 6, 7, 8, 9].map(RefCell::new);
+/// let slice: [RefCell<i32>; 6] = sized_slice_with(original, 6, 8, Default::default);
+/// assert_eq!(slice.map(RefCell::into_inner), [7, 8, 0, 0, 0, 0]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `fill` panics. All elements that were already moved are dropped in
+/// that case.
+#[cfg(feature = "slice")]
+pub fn sized_slice_with<T, F, const ORIGINAL_SIZE: usize, const SLICE_SIZE: usize>(
+    original: [T; ORIGINAL_SIZE],
+    from: usize,
+    till: usize,
+    mut fill: F,
+) -> [T; SLICE_SIZE]
+where
+    F: FnMut() -> T,
+{
+    let mut elements = IntoIterator::into_iter(original).take(till).skip(from);
+    ::core::array::from_fn(|_| elements.next().unwrap_or_else(&mut fill))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{tracked, Tracked};
+    use core::cell::Cell;
+
+    fn values<const SIZE: usize>(array: [Tracked<'_>; SIZE]) -> [usize; SIZE] {
+        array.map(|element| element.0)
+    }
 
     #[test]
     #[cfg(feature = "initialize")]
@@ -555,6 +2038,28 @@ mod tests {
         assert_eq!(initialize_from(|index| 5 + index), [5, 6, 7, 8, 9, 10]);
     }
 
+    #[test]
+    #[cfg(feature = "initialize")]
+    fn init_from_without_default() {
+        use core::num::NonZeroU8;
+
+        let words = ["alpha", "beta", "gamma"];
+        let references: [&str; 3] = initialize_from(|index| words[2 - index]);
+        assert_eq!(references, ["gamma", "beta", "alpha"]);
+
+        let non_zero: [NonZeroU8; 4] =
+            initialize_from(|index| match NonZeroU8::new(index as u8 + 1) {
+                Some(value) => value,
+                None => unreachable!(),
+            });
+        assert_eq!(non_zero.map(NonZeroU8::get), [1, 2, 3, 4]);
+
+        let drops = Cell::new(0);
+        let tracked: [Tracked; 3] = initialize_from(|index| Tracked(index, &drops));
+        assert_eq!(values(tracked), [0, 1, 2]);
+        assert_eq!(drops.get(), 3);
+    }
+
     #[test]
     #[cfg(feature = "initialize")]
     fn init_till() {
@@ -608,6 +2113,102 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "initialize")]
+    fn try_init_from() {
+        assert_eq!(
+            try_initialize_from(|index| Ok::<_, ()>(2 * index), 42),
+            Ok([0, 2, 4, 6])
+        );
+        assert_eq!(
+            try_initialize_from(|index| if index == 2 { Err("stop") } else { Ok(index) }, 42),
+            Err(InitializeError {
+                error: "stop",
+                index: 2,
+                partial: [0, 1, 42, 42, 42],
+            })
+        );
+        assert_eq!(
+            try_initialize_from(|_| Err::<usize, _>(7), 42),
+            Err(InitializeError {
+                error: 7,
+                index: 0,
+                partial: [42; 3],
+            })
+        );
+        assert_eq!(try_initialize_from(|_| Err::<usize, _>(7), 42), Ok([]));
+
+        // The closure is not called again after the first error.
+        let mut calls = 0;
+        let result: Result<[usize; 6], _> = try_initialize_from_with(
+            |index| {
+                calls += 1;
+                if index == 3 {
+                    Err(index)
+                } else {
+                    Ok(index)
+                }
+            },
+            || 0,
+        );
+        assert_eq!(calls, 4);
+        assert_eq!(
+            result,
+            Err(InitializeError {
+                error: 3,
+                index: 3,
+                partial: [0, 1, 2, 0, 0, 0],
+            })
+        );
+
+        let mut fills = 0;
+        let result: Result<[usize; 3], InitializeError<usize, (), 3>> =
+            try_initialize_from_with(Ok, || {
+                fills += 1;
+                0
+            });
+        assert_eq!((result, fills), (Ok([0, 1, 2]), 0));
+    }
+
+    #[test]
+    #[cfg(feature = "initialize")]
+    fn init_stateful() {
+        let mut calls = 0;
+        let counted: [usize; 4] = initialize_from(|index| {
+            calls += 1;
+            index * calls
+        });
+        assert_eq!(counted, [0, 2, 6, 12]);
+        assert_eq!(calls, 4);
+
+        let mut bytes = [3u8, 1, 4, 0, 5].iter().copied();
+        assert_eq!(
+            initialize_from_option(|_| bytes.next().filter(|&byte| byte != 0), 0),
+            ([3, 1, 4, 0, 0, 0], 3)
+        );
+        // The terminator was consumed, the rest of the stream is untouched.
+        assert_eq!(bytes.next(), Some(5));
+    }
+
+    #[test]
+    #[cfg(feature = "initialize")]
+    fn init_scan() {
+        assert_eq!(
+            initialize_scan(0, |sum, index| {
+                *sum += index;
+                *sum
+            }),
+            [0, 1, 3, 6, 10]
+        );
+        assert_eq!(initialize_scan((), |_, index| index), [0usize; 0]);
+
+        let cumulative: [usize; 5] = initialize_scan(1, |product, index| {
+            *product *= index + 1;
+            *product
+        });
+        assert_eq!(cumulative, [1, 2, 6, 24, 120]);
+    }
+
     #[test]
     #[cfg(feature = "drift")]
     fn drift_st() {
@@ -642,6 +2243,23 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "drift")]
+    fn drift_out_of_range() {
+        let array = [1, 2, 3, 4, 5];
+
+        assert_eq!(drift_to_end(array, 4, 2, 0), [2, 3, 4, 0, 0]);
+        assert_eq!(drift_to_end(array, 9, 0, 0), array);
+        assert_eq!(drift_to_end(array, 9, 1, 0), [2, 3, 4, 5, 0]);
+        assert_eq!(drift_to_end(array, 3, 5, 0), [0; 5]);
+        assert_eq!(drift_to_end(array, usize::MAX, usize::MAX, 0), [0; 5]);
+
+        assert_eq!(drift_to_begin(array, 1, 3, 0), [0, 0, 0, 2, 3]);
+        assert_eq!(drift_to_begin(array, 5, 0, 0), [0; 5]);
+        assert_eq!(drift_to_begin(array, 0, 5, 0), [0; 5]);
+        assert_eq!(drift_to_begin(array, usize::MAX, usize::MAX, 0), [0; 5]);
+    }
+
     #[test]
     #[cfg(feature = "resize")]
     fn arr_resize() {
@@ -668,6 +2286,7 @@ mod tests {
         );
         assert_eq!(superimpose(array, [0, 1, 2, 3], 10), array);
         assert_eq!(superimpose(array, [0, 1, 2, 3], 0), array);
+        assert_eq!(superimpose(array, [0, 1, 2, 3], usize::MAX), array);
     }
 
     #[test]
@@ -724,4 +2343,326 @@ mod tests {
             ([4, 5, 6, 7, 0, 1])
         );
     }
+
+    #[test]
+    #[cfg(feature = "initialize")]
+    fn init_with() {
+        let drops = Cell::new(0);
+        let (array, length): ([Tracked; 5], usize) = initialize_till_with(
+            |index| Tracked(index, &drops),
+            Tracked(3, &drops),
+            || Tracked(42, &drops),
+        );
+        assert_eq!((values(array), length), ([0, 1, 2, 42, 42], 3));
+        assert_eq!(drops.get(), 7);
+
+        let (array, length): ([Tracked; 4], usize) = initialize_from_option_with(
+            |index| {
+                if index == 2 {
+                    None
+                } else {
+                    Some(Tracked(index, &drops))
+                }
+            },
+            || Tracked(42, &drops),
+        );
+        assert_eq!((values(array), length), ([0, 1, 42, 42], 2));
+
+        let (array, length): ([Tracked; 4], usize) = initialize_from_result_with(
+            |index| {
+                if index == 9 {
+                    Err(())
+                } else {
+                    Ok(Tracked(index, &drops))
+                }
+            },
+            || Tracked(42, &drops),
+        );
+        assert_eq!((values(array), length), ([0, 1, 2, 3], 4));
+    }
+
+    #[test]
+    #[cfg(feature = "drift")]
+    fn drift_with() {
+        let drops = Cell::new(0);
+        let array = drift_to_begin_with(tracked::<6>(&drops), 2, 1, || Tracked(42, &drops));
+        assert_eq!(drops.get(), 2);
+        assert_eq!(values(array), [42, 2, 3, 4, 5, 42]);
+
+        let array = drift_to_end_with(tracked::<6>(&drops), 3, 1, || Tracked(42, &drops));
+        assert_eq!(values(array), [42, 42, 0, 1, 2, 42]);
+
+        // The slice and margin don't fit, so the first elements of the slice are dropped.
+        let array = drift_to_end_with(tracked::<4>(&drops), 4, 2, || Tracked(42, &drops));
+        assert_eq!(values(array), [2, 3, 42, 42]);
+        let array = drift_to_end_with(tracked::<4>(&drops), 9, 9, || Tracked(42, &drops));
+        assert_eq!(values(array), [42; 4]);
+        let array = drift_to_begin_with(tracked::<4>(&drops), 9, 9, || Tracked(42, &drops));
+        assert_eq!(values(array), [42; 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "resize")]
+    fn arr_resize_with() {
+        let drops = Cell::new(0);
+        let array: [Tracked; 3] = array_resize_with(tracked::<5>(&drops), || unreachable!());
+        assert_eq!(drops.get(), 2);
+        assert_eq!(values(array), [0, 1, 2]);
+
+        let array: [Tracked; 4] = array_resize_with(tracked::<2>(&drops), || Tracked(42, &drops));
+        assert_eq!(values(array), [0, 1, 42, 42]);
+    }
+
+    #[test]
+    #[cfg(feature = "superimpose")]
+    fn super_impose_move() {
+        let drops = Cell::new(0);
+        let array = superimpose_move(tracked::<5>(&drops), tracked::<3>(&drops), 3);
+        assert_eq!(drops.get(), 3);
+        assert_eq!(values(array), [0, 1, 2, 0, 1]);
+
+        let array = superimpose_move(tracked::<2>(&drops), tracked::<3>(&drops), 7);
+        assert_eq!(values(array), [0, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "join")]
+    fn join_arrays_with() {
+        let drops = Cell::new(0);
+        let array: [Tracked; 5] = join_with(
+            tracked::<3>(&drops),
+            tracked::<3>(&drops),
+            || unreachable!(),
+        );
+        assert_eq!(drops.get(), 1);
+        assert_eq!(values(array), [0, 1, 2, 0, 1]);
+
+        let array: [Tracked; 7] = join_with(tracked::<3>(&drops), tracked::<2>(&drops), || {
+            Tracked(42, &drops)
+        });
+        assert_eq!(values(array), [0, 1, 2, 0, 1, 42, 42]);
+    }
+
+    #[test]
+    #[cfg(feature = "splice")]
+    fn splice_arrays_with() {
+        let drops = Cell::new(0);
+        let (left, right): ([Tracked; 2], [Tracked; 2]) =
+            splice_with(tracked::<5>(&drops), || unreachable!());
+        assert_eq!(drops.get(), 1);
+        assert_eq!((values(left), values(right)), ([0, 1], [2, 3]));
+
+        let (left, right): ([Tracked; 2], [Tracked; 3]) =
+            splice_with(tracked::<3>(&drops), || Tracked(42, &drops));
+        assert_eq!((values(left), values(right)), ([0, 1], [2, 42, 42]));
+    }
+
+    #[test]
+    #[cfg(feature = "slice")]
+    fn sized_slices_with() {
+        let drops = Cell::new(0);
+        let array: [Tracked; 3] = sized_slice_with(tracked::<8>(&drops), 4, 7, || unreachable!());
+        assert_eq!(drops.get(), 5);
+        assert_eq!(values(array), [4, 5, 6]);
+
+        let array: [Tracked; 4] =
+            sized_slice_with(tracked::<8>(&drops), 6, 10, || Tracked(42, &drops));
+        assert_eq!(values(array), [6, 7, 42, 42]);
+    }
+
+    #[test]
+    #[cfg(feature = "resize")]
+    fn panicking_fill_drops_elements() {
+        extern crate std;
+
+        let drops = Cell::new(0);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _: [Tracked; 4] = array_resize_with(tracked::<2>(&drops), || panic!());
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn const_contexts() {
+        #[cfg(feature = "drift")]
+        {
+            const BEGIN: [u8; 5] = drift_to_begin([1, 2, 3, 4, 5], 2, 1, 0);
+            const END: [u8; 5] = drift_to_end([1, 2, 3, 4, 5], 2, 1, 0);
+            assert_eq!(BEGIN, [0, 3, 4, 5, 0]);
+            assert_eq!(END, [0, 0, 1, 2, 0]);
+        }
+
+        #[cfg(feature = "resize")]
+        {
+            const RESIZED: [u8; 4] = array_resize([1, 2], 9);
+            assert_eq!(RESIZED, [1, 2, 9, 9]);
+        }
+
+        #[cfg(feature = "superimpose")]
+        {
+            const SUPERIMPOSED: [u8; 4] = superimpose([0; 4], [1, 2], 1);
+            assert_eq!(SUPERIMPOSED, [0, 1, 2, 0]);
+        }
+
+        #[cfg(feature = "join")]
+        {
+            const JOINED: [u8; 5] = join([1, 2], [3, 4], 0);
+            assert_eq!(JOINED, [1, 2, 3, 4, 0]);
+        }
+
+        #[cfg(feature = "splice")]
+        {
+            const SPLICED: ([u8; 2], [u8; 3]) = splice([1, 2, 3, 4], 0);
+            assert_eq!(SPLICED, ([1, 2], [3, 4, 0]));
+        }
+
+        #[cfg(feature = "slice")]
+        {
+            const SLICED: [u8; 3] = sized_slice([1, 2, 3, 4], 2, 4, 0);
+            assert_eq!(SLICED, [3, 4, 0]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "resize")]
+    fn try_arr_resize() {
+        let array: [usize; 4] = initialize_from(|index| index);
+        assert_eq!(try_array_resize(array), Ok(array));
+        assert_eq!(
+            try_array_resize::<_, 4, 1>(array),
+            Err(SizeError::Truncated { dropped: 3 })
+        );
+        assert_eq!(
+            try_array_resize::<_, 4, 6>(array),
+            Err(SizeError::Padded { padded: 2 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "superimpose")]
+    fn try_super_impose() {
+        let array = [0; 6];
+        assert_eq!(try_superimpose(array, [1, 2], 0), Ok([1, 2, 0, 0, 0, 0]));
+        assert_eq!(try_superimpose(array, [1, 2], 4), Ok([0, 0, 0, 0, 1, 2]));
+        assert_eq!(try_superimpose(array, [], 6), Ok(array));
+        assert_eq!(
+            try_superimpose(array, [1, 2], 6),
+            Err(SizeError::Truncated { dropped: 2 })
+        );
+        assert_eq!(
+            try_superimpose(array, [1, 2], usize::MAX),
+            Err(SizeError::OutOfRange {
+                index: usize::MAX,
+                size: 6
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "join")]
+    fn try_join_arrays() {
+        assert_eq!(try_join([4, 5], [0, 1, 2]), Ok([4, 5, 0, 1, 2]));
+        assert_eq!(
+            try_join::<_, 2, 3, 7>([4, 5], [0, 1, 2]),
+            Err(SizeError::Padded { padded: 2 })
+        );
+        assert_eq!(
+            try_join::<_, 2, 3, 1>([4, 5], [0, 1, 2]),
+            Err(SizeError::Truncated { dropped: 4 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "splice")]
+    fn try_splice_arrays() {
+        assert_eq!(try_splice([4, 5, 0, 1, 2]), Ok(([4, 5], [0, 1, 2])));
+        assert_eq!(
+            try_splice::<_, 5, 2, 2>([4, 5, 0, 1, 2]),
+            Err(SizeError::Truncated { dropped: 1 })
+        );
+        assert_eq!(
+            try_splice::<_, 5, 4, 4>([4, 5, 0, 1, 2]),
+            Err(SizeError::Padded { padded: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "slice")]
+    fn try_sized_slices() {
+        let array = [4, 5, 6, 7, 0, 1, 2, 3];
+        assert_eq!(try_sized_slice(array, 4, 8), Ok([0, 1, 2, 3]));
+        assert_eq!(try_sized_slice(array, 8, 8), Ok([]));
+        assert_eq!(
+            try_sized_slice::<_, 8, 4>(array, 4, 9),
+            Err(SizeError::OutOfRange { index: 9, size: 8 })
+        );
+        assert_eq!(
+            try_sized_slice::<_, 8, 4>(array, 0, 8),
+            Err(SizeError::Truncated { dropped: 4 })
+        );
+        assert_eq!(
+            try_sized_slice::<_, 8, 4>(array, 6, 3),
+            Err(SizeError::ReversedRange { from: 6, till: 3 })
+        );
+        assert_eq!(
+            try_sized_slice::<_, 8, 0>(array, 9, 3),
+            Err(SizeError::ReversedRange { from: 9, till: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "join")]
+    fn join_arrays_exact() {
+        assert_eq!(join_exact([4, 5, 6], [0, 1]), [4, 5, 6, 0, 1]);
+        assert_eq!(join_exact([], [0, 1]), [0, 1]);
+        assert_eq!(join_exact::<u8, 0, 0, 0>([], []), []);
+    }
+
+    #[test]
+    #[cfg(feature = "splice")]
+    fn splice_arrays_exact() {
+        assert_eq!(splice_exact([4, 5, 6, 0, 1]), ([4, 5, 6], [0, 1]));
+        assert_eq!(splice_exact([4, 5]), ([], [4, 5]));
+        assert_eq!(splice_exact::<u8, 0, 0, 0>([]), ([], []));
+    }
+
+    #[test]
+    #[cfg(feature = "drift")]
+    fn drift_in_place() {
+        let array: [usize; 7] = initialize_from(|index| index);
+        for (index, margin) in [(0, 0), (3, 2), (2, 5), (7, 0), (9, 1), (4, 9)] {
+            let mut in_place = array;
+            drift_to_begin_in_place(&mut in_place, index, margin, 42);
+            assert_eq!(in_place, drift_to_begin(array, index, margin, 42));
+
+            let mut in_place = array;
+            drift_to_end_in_place(&mut in_place, index, margin, 42);
+            assert_eq!(in_place, drift_to_end(array, index, margin, 42));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "resize")]
+    fn arr_resize_into() {
+        let array: [usize; 10] = initialize_from(|index| index);
+        let mut output = [0; 8];
+        array_resize_into(&array, &mut output, 42);
+        assert_eq!(output, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+        let mut output = [0; 12];
+        array_resize_into(&array, &mut output, 42);
+        assert_eq!(output, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 42, 42]);
+    }
+
+    #[test]
+    #[cfg(feature = "superimpose")]
+    fn super_impose_in_place() {
+        let array: [usize; 10] = initialize_from(|index| index);
+        for starting_from in [0, 4, 8, 10, usize::MAX] {
+            let mut in_place = array;
+            superimpose_in_place(&mut in_place, &[0, 1, 2, 3], starting_from);
+            assert_eq!(in_place, superimpose(array, [0, 1, 2, 3], starting_from));
+        }
+    }
 }
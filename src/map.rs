@@ -0,0 +1,216 @@
+//! Transformations between sized arrays of the same size.
+
+use crate::ArrayVec;
+
+/// Map every element of an array together with its index.
+///
+/// Works like [`array::map`](primitive@array#method.map), but the closure also receives the index
+/// of the element. The elements are visited in order.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::map_indexed;
+///
+/// assert_eq!(map_indexed([10, 20, 30], |index, value| index + value), [10, 21, 32]);
+///
+/// let labels = map_indexed(["a", "b"], |index, name| (index, name));
+/// assert_eq!(labels, [(0, "a"), (1, "b")]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `f` panics. All elements are dropped in that case.
+pub fn map_indexed<T, U, F, const SIZE: usize>(array: [T; SIZE], mut f: F) -> [U; SIZE]
+where
+    F: FnMut(usize, T) -> U,
+{
+    let mut index = 0;
+    array.map(|element| {
+        let mapped = f(index, element);
+        index += 1;
+        mapped
+    })
+}
+
+/// Combine the elements of two arrays of the same size with a closure.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::zip_with;
+///
+/// assert_eq!(zip_with([1, 2, 3], [10, 20, 30], |a, b| a * b), [10, 40, 90]);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `f` panics. All elements are dropped in that case.
+pub fn zip_with<A, B, U, F, const SIZE: usize>(
+    left: [A; SIZE],
+    right: [B; SIZE],
+    mut f: F,
+) -> [U; SIZE]
+where
+    F: FnMut(A, B) -> U,
+{
+    let mut zipped = ArrayVec::new();
+    for (a, b) in IntoIterator::into_iter(left).zip(right) {
+        let _ = zipped.push(f(a, b));
+    }
+
+    // SAFETY: Both arrays have `SIZE` elements, so every one of the `SIZE` pairs pushed an element.
+    unsafe { zipped.into_inner_unchecked() }
+}
+
+/// Pair up the elements of two arrays of the same size.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::zip;
+///
+/// assert_eq!(zip([1, 2], ['a', 'b']), [(1, 'a'), (2, 'b')]);
+/// ```
+pub fn zip<A, B, const SIZE: usize>(left: [A; SIZE], right: [B; SIZE]) -> [(A, B); SIZE] {
+    zip_with(left, right, |a, b| (a, b))
+}
+
+/// Split an array of pairs into two arrays of the same size.
+///
+/// The inverse of [`zip`](crate::zip).
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::unzip;
+///
+/// assert_eq!(unzip([(1, 'a'), (2, 'b')]), ([1, 2], ['a', 'b']));
+/// ```
+pub fn unzip<A, B, const SIZE: usize>(pairs: [(A, B); SIZE]) -> ([A; SIZE], [B; SIZE]) {
+    let mut left = ArrayVec::new();
+    let mut right = ArrayVec::new();
+    for (a, b) in pairs {
+        let _ = left.push(a);
+        let _ = right.push(b);
+    }
+
+    // SAFETY: Every one of the `SIZE` pairs pushed an element to both vectors.
+    unsafe { (left.into_inner_unchecked(), right.into_inner_unchecked()) }
+}
+
+/// Map every element of an array with a fallible closure, stopping at the first error.
+///
+/// Returns the mapped array if `f` succeeds for every element. Otherwise the index of the first
+/// element for which `f` failed is returned along with its error. The closure is not called for
+/// the elements after that.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::try_map;
+///
+/// assert_eq!(try_map(["1", "2", "3"], str::parse::<u8>), Ok([1, 2, 3]));
+///
+/// let (index, _) = try_map(["1", "two", "3"], str::parse::<u8>).unwrap_err();
+/// assert_eq!(index, 1);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `f` panics. All elements are dropped in that case.
+pub fn try_map<T, U, E, F, const SIZE: usize>(
+    array: [T; SIZE],
+    mut f: F,
+) -> Result<[U; SIZE], (usize, E)>
+where
+    F: FnMut(T) -> Result<U, E>,
+{
+    let mut mapped = ArrayVec::new();
+    for (index, element) in IntoIterator::into_iter(array).enumerate() {
+        let _ = mapped.push(f(element).map_err(|error| (index, error))?);
+    }
+
+    // SAFETY: Every one of the `SIZE` elements was mapped and pushed.
+    Ok(unsafe { mapped.into_inner_unchecked() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Tracked;
+    use core::cell::Cell;
+
+    #[test]
+    fn indexed() {
+        assert_eq!(
+            map_indexed([5, 5, 5], |index, value| index * value),
+            [0, 5, 10]
+        );
+        assert_eq!(map_indexed([(); 0], |index, _| index), []);
+    }
+
+    #[test]
+    fn zipping() {
+        assert_eq!(zip([1, 2, 3], [4, 5, 6]), [(1, 4), (2, 5), (3, 6)]);
+        assert_eq!(unzip(zip([1, 2, 3], [4, 5, 6])), ([1, 2, 3], [4, 5, 6]));
+        assert_eq!(zip_with(["a", "bc"], [1, 2], |s, n| s.len() + n), [2, 4]);
+        assert_eq!(unzip::<u8, u8, 0>([]), ([], []));
+
+        let drops = Cell::new(0);
+        let (left, right) = unzip([(Tracked(1, &drops), Tracked(2, &drops))]);
+        assert_eq!((left[0].0, right[0].0, drops.get()), (1, 2, 0));
+    }
+
+    #[test]
+    fn try_mapping() {
+        assert_eq!(
+            try_map([1, 2, 3], |value| Ok::<_, ()>(value * 2)),
+            Ok([2, 4, 6])
+        );
+        assert_eq!(
+            try_map([1, 2, 3, 4], |value| if value % 2 == 0 {
+                Err(value)
+            } else {
+                Ok(value)
+            }),
+            Err((1, 2))
+        );
+
+        let calls = Cell::new(0);
+        let drops = Cell::new(0);
+        let result = try_map(
+            [0, 1, 2, 3].map(|value| Tracked(value, &drops)),
+            |element| {
+                calls.set(calls.get() + 1);
+                if element.0 == 2 {
+                    Err(())
+                } else {
+                    Ok(element)
+                }
+            },
+        );
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 3);
+        // All elements are dropped, whether mapped, failed or never visited.
+        assert_eq!(drops.get(), 4);
+    }
+    #[test]
+    fn panicking_closure_drops_elements() {
+        extern crate std;
+
+        let drops = Cell::new(0);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let left = [0, 1, 2].map(|value| Tracked(value, &drops));
+            let right = [3, 4, 5].map(|value| Tracked(value, &drops));
+            zip_with(left, right, |a, b| {
+                if a.0 == 1 {
+                    panic!();
+                }
+                (a, b)
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 6);
+    }
+}
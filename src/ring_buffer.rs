@@ -0,0 +1,502 @@
+//! A heapless ring buffer with a fixed capacity.
+//!
+//! See [`RingBuffer`] for more information.
+
+use core::fmt;
+use core::iter::{Chain, FromIterator, FusedIterator};
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::slice;
+
+/// A first-in-first-out queue with a fixed `CAPACITY`, which stores its elements in a sized array.
+///
+/// Pushing onto a full ring buffer overwrites its oldest element. This makes it a sliding window
+/// over the last `CAPACITY` elements, without having to move all elements over with
+/// [`drift_to_begin`](crate::drift_to_begin) on every new element. The elements can be linearized
+/// back into a sized array with [`to_array`](RingBuffer::to_array) or
+/// [`as_contiguous`](RingBuffer::as_contiguous).
+///
+/// None of the operations panic. Indexing is done through [`get`](RingBuffer::get) and
+/// [`get_mut`](RingBuffer::get_mut), which return [`None`] when out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::RingBuffer;
+///
+/// let mut window: RingBuffer<u16, 4> = RingBuffer::new();
+/// for sample in [10, 20, 30, 40, 50] {
+///     window.push(sample);
+/// }
+///
+/// // The oldest sample has been overwritten.
+/// assert_eq!(window.to_array(0), [20, 30, 40, 50]);
+/// assert_eq!(window.peek(), Some(&20));
+/// assert_eq!(window.get(3), Some(&50));
+///
+/// assert_eq!(window.pop(), Some(20));
+/// assert_eq!(window.iter().sum::<u16>(), 120);
+/// ```
+pub struct RingBuffer<T, const CAPACITY: usize> {
+    buffer: [MaybeUninit<T>; CAPACITY],
+    head: usize,
+    length: usize,
+}
+
+/// Reinterpret a slice of initialized elements.
+///
+/// # Safety
+///
+/// All elements of `slice` must be initialized.
+unsafe fn assume_init<T>(slice: &[MaybeUninit<T>]) -> &[T] {
+    slice::from_raw_parts(slice.as_ptr() as *const T, slice.len())
+}
+
+/// Reinterpret a mutable slice of initialized elements.
+///
+/// # Safety
+///
+/// All elements of `slice` must be initialized.
+unsafe fn assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut T, slice.len())
+}
+
+impl<T, const CAPACITY: usize> RingBuffer<T, CAPACITY> {
+    /// Create a new empty ring buffer.
+    pub const fn new() -> Self {
+        RingBuffer {
+            buffer: [const { MaybeUninit::uninit() }; CAPACITY],
+            head: 0,
+            length: 0,
+        }
+    }
+
+    /// The amount of elements in the ring buffer.
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Whether the ring buffer contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Whether the ring buffer contains `CAPACITY` elements, meaning the next push overwrites an
+    /// element.
+    pub const fn is_full(&self) -> bool {
+        self.length == CAPACITY
+    }
+
+    /// The maximum amount of elements the ring buffer can contain, which is `CAPACITY`.
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// The position in the buffer of the element with logical `index`.
+    ///
+    /// Only valid for an `index` less than `CAPACITY`.
+    const fn physical(&self, index: usize) -> usize {
+        let position = self.head + index;
+        if position >= CAPACITY {
+            position - CAPACITY
+        } else {
+            position
+        }
+    }
+
+    /// Push an element as the newest element of the ring buffer.
+    ///
+    /// If the ring buffer is full, the oldest element is overwritten and returned.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if CAPACITY == 0 {
+            return Some(value);
+        }
+
+        if self.is_full() {
+            let slot = &mut self.buffer[self.head];
+            // SAFETY: The ring buffer is full, so the oldest element at `head` is initialized. It
+            // is replaced by `value` and no longer part of the ring buffer.
+            let oldest = unsafe { slot.assume_init_read() };
+            slot.write(value);
+            self.head = self.physical(1);
+            return Some(oldest);
+        }
+
+        let position = self.physical(self.length);
+        self.buffer[position].write(value);
+        self.length += 1;
+        None
+    }
+
+    /// Remove the oldest element of the ring buffer and return it, or [`None`] if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // SAFETY: The ring buffer is not empty, so the oldest element at `head` is initialized.
+        // It is no longer part of the ring buffer afterwards.
+        let oldest = unsafe { self.buffer[self.head].assume_init_read() };
+        self.head = self.physical(1);
+        self.length -= 1;
+        Some(oldest)
+    }
+
+    /// Remove the newest element of the ring buffer and return it, or [`None`] if it is empty.
+    pub fn pop_newest(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.length -= 1;
+        let position = self.physical(self.length);
+        // SAFETY: The newest element is initialized and no longer part of the ring buffer.
+        Some(unsafe { self.buffer[position].assume_init_read() })
+    }
+
+    /// A reference to the oldest element of the ring buffer, or [`None`] if it is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// A reference to the newest element of the ring buffer, or [`None`] if it is empty.
+    pub fn peek_newest(&self) -> Option<&T> {
+        self.get(self.length.checked_sub(1)?)
+    }
+
+    /// A reference to the element with logical `index`, where `0` is the oldest element. Returns
+    /// [`None`] if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.length {
+            return None;
+        }
+
+        // SAFETY: All elements with a logical index below `length` are initialized.
+        Some(unsafe { self.buffer[self.physical(index)].assume_init_ref() })
+    }
+
+    /// A mutable reference to the element with logical `index`, where `0` is the oldest element.
+    /// Returns [`None`] if `index` is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.length {
+            return None;
+        }
+
+        let position = self.physical(index);
+        // SAFETY: All elements with a logical index below `length` are initialized.
+        Some(unsafe { self.buffer[position].assume_init_mut() })
+    }
+
+    /// The elements of the ring buffer from oldest to newest, as two consecutive slices.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let end = self.head + self.length;
+        // SAFETY: The elements `head..head + length`, wrapping around at `CAPACITY`, are
+        // initialized.
+        unsafe {
+            if end <= CAPACITY {
+                (assume_init(&self.buffer[self.head..end]), &[])
+            } else {
+                (
+                    assume_init(&self.buffer[self.head..]),
+                    assume_init(&self.buffer[..end - CAPACITY]),
+                )
+            }
+        }
+    }
+
+    /// The elements of the ring buffer from oldest to newest, as two consecutive mutable slices.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let end = self.head + self.length;
+        // SAFETY: See `as_slices`.
+        unsafe {
+            if end <= CAPACITY {
+                (assume_init_mut(&mut self.buffer[self.head..end]), &mut [])
+            } else {
+                let (wrapped, rest) = self.buffer.split_at_mut(self.head);
+                (
+                    assume_init_mut(rest),
+                    assume_init_mut(&mut wrapped[..end - CAPACITY]),
+                )
+            }
+        }
+    }
+
+    /// Rotate the elements of the ring buffer in place so that they are stored consecutively,
+    /// and return them as a single slice from oldest to newest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 3> = [1, 2, 3, 4].iter().copied().collect();
+    /// assert_eq!(ring.as_slices(), (&[2, 3][..], &[4][..]));
+    /// assert_eq!(ring.as_contiguous(), [2, 3, 4]);
+    /// ```
+    pub fn as_contiguous(&mut self) -> &mut [T] {
+        if self.head + self.length > CAPACITY {
+            self.buffer.rotate_left(self.head);
+            self.head = 0;
+        }
+
+        self.as_mut_slices().0
+    }
+
+    /// An iterator over the elements of the ring buffer from oldest to newest.
+    pub fn iter(&self) -> Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        let (oldest, newest) = self.as_slices();
+        oldest.iter().chain(newest.iter())
+    }
+
+    /// A mutable iterator over the elements of the ring buffer from oldest to newest.
+    pub fn iter_mut(&mut self) -> Chain<slice::IterMut<'_, T>, slice::IterMut<'_, T>> {
+        let (oldest, newest) = self.as_mut_slices();
+        oldest.iter_mut().chain(newest.iter_mut())
+    }
+
+    /// Remove all elements from the ring buffer.
+    pub fn clear(&mut self) {
+        let (oldest, newest) = self.as_mut_slices();
+        let (oldest, newest) = (oldest as *mut [T], newest as *mut [T]);
+        // The length is reset first, so that a panicking destructor cannot cause a double drop.
+        self.head = 0;
+        self.length = 0;
+        // SAFETY: Both slices are initialized and no longer part of the ring buffer.
+        unsafe {
+            ptr::drop_in_place(oldest);
+            ptr::drop_in_place(newest);
+        }
+    }
+
+    /// Create a sized array containing the elements of the ring buffer from oldest to newest,
+    /// filling the remaining elements with the `fill` value.
+    ///
+    /// This is the same layout as [`drift_to_begin`](crate::drift_to_begin) produces, so the
+    /// result can directly be used with the other utilities of this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use array_utils::RingBuffer;
+    ///
+    /// let mut ring: RingBuffer<u8, 4> = RingBuffer::new();
+    /// ring.push(1);
+    /// ring.push(2);
+    /// assert_eq!(ring.to_array(0), [1, 2, 0, 0]);
+    /// ```
+    pub fn to_array(&self, fill: T) -> [T; CAPACITY]
+    where
+        T: Copy,
+    {
+        let (oldest, newest) = self.as_slices();
+        let mut buffer = [fill; CAPACITY];
+        buffer[..oldest.len()].copy_from_slice(oldest);
+        buffer[oldest.len()..self.length].copy_from_slice(newest);
+        buffer
+    }
+}
+
+impl<T, const CAPACITY: usize> Drop for RingBuffer<T, CAPACITY> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for RingBuffer<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const CAPACITY: usize> Clone for RingBuffer<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const CAPACITY: usize> fmt::Debug for RingBuffer<T, CAPACITY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize> PartialEq for RingBuffer<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for RingBuffer<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> From<[T; CAPACITY]> for RingBuffer<T, CAPACITY> {
+    /// Create a full ring buffer from a sized array, with the first element as the oldest.
+    fn from(array: [T; CAPACITY]) -> Self {
+        IntoIterator::into_iter(array).collect()
+    }
+}
+
+impl<T, const CAPACITY: usize> Extend<T> for RingBuffer<T, CAPACITY> {
+    /// Push all elements of `iter`, overwriting the oldest elements when the ring buffer is full.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> FromIterator<T> for RingBuffer<T, CAPACITY> {
+    /// Collect the last `CAPACITY` elements of `iter`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ring = Self::new();
+        ring.extend(iter);
+        ring
+    }
+}
+
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a RingBuffer<T, CAPACITY> {
+    type Item = &'a T;
+    type IntoIter = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a mut RingBuffer<T, CAPACITY> {
+    type Item = &'a mut T;
+    type IntoIter = Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const CAPACITY: usize> IntoIterator for RingBuffer<T, CAPACITY> {
+    type Item = T;
+    type IntoIter = IntoIter<T, CAPACITY>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+/// An iterator that moves the elements out of a [`RingBuffer`] from oldest to newest.
+///
+/// Created by the [`into_iter`](IntoIterator::into_iter) method of [`RingBuffer`].
+#[derive(Debug, Clone)]
+pub struct IntoIter<T, const CAPACITY: usize>(RingBuffer<T, CAPACITY>);
+
+impl<T, const CAPACITY: usize> Iterator for IntoIter<T, CAPACITY> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<T, const CAPACITY: usize> DoubleEndedIterator for IntoIter<T, CAPACITY> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.pop_newest()
+    }
+}
+
+impl<T, const CAPACITY: usize> ExactSizeIterator for IntoIter<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> FusedIterator for IntoIter<T, CAPACITY> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Tracked;
+    use core::cell::Cell;
+
+    #[test]
+    fn push_pop() {
+        let mut ring: RingBuffer<usize, 3> = RingBuffer::new();
+        assert_eq!(ring.pop(), None);
+        assert_eq!(ring.peek(), None);
+        assert_eq!(ring.push(1), None);
+        assert_eq!(ring.push(2), None);
+        assert_eq!(ring.push(3), None);
+        assert!(ring.is_full());
+        assert_eq!(ring.push(4), Some(1));
+        assert_eq!(ring.push(5), Some(2));
+        assert_eq!((ring.peek(), ring.peek_newest()), (Some(&3), Some(&5)));
+
+        assert_eq!(ring.pop(), Some(3));
+        assert_eq!(ring.pop_newest(), Some(5));
+        assert_eq!(ring.pop(), Some(4));
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut ring: RingBuffer<usize, 0> = RingBuffer::new();
+        assert_eq!(ring.push(1), Some(1));
+        assert_eq!(ring.pop(), None);
+        assert_eq!(ring.to_array(0), []);
+        assert_eq!(ring.as_contiguous(), []);
+    }
+
+    #[test]
+    fn indexed() {
+        let mut ring: RingBuffer<usize, 4> = (0..6).collect();
+        assert_eq!(ring.get(0), Some(&2));
+        assert_eq!(ring.get(3), Some(&5));
+        assert_eq!(ring.get(4), None);
+
+        *ring.get_mut(1).unwrap() = 9;
+        assert_eq!(ring.iter().copied().collect::<RingBuffer<usize, 4>>(), ring);
+        assert_eq!(ring.to_array(0), [2, 9, 4, 5]);
+    }
+
+    #[test]
+    fn linearize() {
+        let mut ring: RingBuffer<usize, 5> = (0..7).collect();
+        ring.pop();
+        assert_eq!(ring.as_slices(), (&[3, 4][..], &[5, 6][..]));
+        assert_eq!(ring.to_array(42), [3, 4, 5, 6, 42]);
+
+        assert_eq!(ring.as_contiguous(), [3, 4, 5, 6]);
+        assert_eq!(ring.as_slices(), (&[3, 4, 5, 6][..], &[][..]));
+        ring.push(7);
+        ring.push(8);
+        assert_eq!(ring.to_array(42), [4, 5, 6, 7, 8]);
+
+        for element in &mut ring {
+            *element += 1;
+        }
+        assert_eq!(
+            ring.into_iter().rev().collect::<RingBuffer<usize, 5>>(),
+            [9, 8, 7, 6, 5].into()
+        );
+    }
+
+    #[test]
+    fn drops() {
+        let drops = Cell::new(0);
+
+        let mut ring: RingBuffer<Tracked, 3> = (0..5).map(|index| Tracked(index, &drops)).collect();
+        assert_eq!(drops.get(), 2);
+        assert_eq!(ring.peek().map(|element| element.0), Some(2));
+
+        drop(ring.push(Tracked(5, &drops)));
+        assert_eq!(drops.get(), 3);
+
+        let mut iter = ring.into_iter();
+        drop(iter.next());
+        assert_eq!(drops.get(), 4);
+        drop(iter);
+        assert_eq!(drops.get(), 6);
+
+        let mut ring: RingBuffer<Tracked, 3> = RingBuffer::new();
+        ring.push(Tracked(0, &drops));
+        ring.push(Tracked(1, &drops));
+        drop(ring);
+        assert_eq!(drops.get(), 8);
+    }
+}
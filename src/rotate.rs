@@ -0,0 +1,148 @@
+//! Rotating the elements of sized arrays.
+
+/// Rotate an array `k` places to the left.
+///
+/// The first `k` elements are moved to the back. Unlike
+/// [`drift_to_begin`](crate::drift_to_begin), no elements are dropped. `k` is taken modulo the
+/// size of the array, so this never panics.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::rotate_left;
+///
+/// assert_eq!(rotate_left([1, 2, 3, 4, 5], 2), [3, 4, 5, 1, 2]);
+/// assert_eq!(rotate_left([1, 2, 3, 4, 5], 7), [3, 4, 5, 1, 2]);
+/// ```
+pub fn rotate_left<T, const SIZE: usize>(mut array: [T; SIZE], k: usize) -> [T; SIZE] {
+    if SIZE != 0 {
+        array.rotate_left(k % SIZE);
+    }
+    array
+}
+
+/// Rotate an array `k` places to the right.
+///
+/// The last `k` elements are moved to the front. Unlike [`drift_to_end`](crate::drift_to_end), no
+/// elements are dropped. `k` is taken modulo the size of the array, so this never panics.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::rotate_right;
+///
+/// assert_eq!(rotate_right([1, 2, 3, 4, 5], 2), [4, 5, 1, 2, 3]);
+/// assert_eq!(rotate_right([1, 2, 3, 4, 5], 12), [4, 5, 1, 2, 3]);
+/// ```
+pub fn rotate_right<T, const SIZE: usize>(mut array: [T; SIZE], k: usize) -> [T; SIZE] {
+    if SIZE != 0 {
+        array.rotate_right(k % SIZE);
+    }
+    array
+}
+
+/// Rotate an array `K` places to the left, in a `const` context.
+///
+/// Works the same as [`rotate_left`](crate::rotate_left), but the amount is a const generic
+/// parameter, which allows it to be used in `const` and `static` items.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::rotate_left_const;
+///
+/// const ROTATED: [u8; 4] = rotate_left_const::<1, _, 4>([1, 2, 3, 4]);
+/// assert_eq!(ROTATED, [2, 3, 4, 1]);
+/// ```
+pub const fn rotate_left_const<const K: usize, T, const SIZE: usize>(array: [T; SIZE]) -> [T; SIZE]
+where
+    T: Copy,
+{
+    if SIZE == 0 {
+        return array;
+    }
+
+    let mut buffer = array;
+    let mut i = 0;
+    while i < SIZE {
+        buffer[i] = array[(i + K % SIZE) % SIZE];
+        i += 1;
+    }
+    buffer
+}
+
+/// Rotate an array `K` places to the right, in a `const` context.
+///
+/// Works the same as [`rotate_right`](crate::rotate_right), but the amount is a const generic
+/// parameter, which allows it to be used in `const` and `static` items.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::rotate_right_const;
+///
+/// const ROTATED: [u8; 4] = rotate_right_const::<1, _, 4>([1, 2, 3, 4]);
+/// assert_eq!(ROTATED, [4, 1, 2, 3]);
+/// ```
+pub const fn rotate_right_const<const K: usize, T, const SIZE: usize>(array: [T; SIZE]) -> [T; SIZE]
+where
+    T: Copy,
+{
+    if SIZE == 0 {
+        return array;
+    }
+
+    let mut buffer = array;
+    let mut i = 0;
+    while i < SIZE {
+        buffer[(i + K % SIZE) % SIZE] = array[i];
+        i += 1;
+    }
+    buffer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime() {
+        assert_eq!(rotate_left([1, 2, 3], 0), [1, 2, 3]);
+        assert_eq!(rotate_left([1, 2, 3], 3), [1, 2, 3]);
+        assert_eq!(rotate_left([1, 2, 3], usize::MAX), [1, 2, 3]);
+        assert_eq!(rotate_left([1, 2, 3, 4], usize::MAX), [4, 1, 2, 3]);
+        assert_eq!(rotate_right([1, 2, 3], 1), [3, 1, 2]);
+        assert_eq!(rotate_right([1, 2, 3], usize::MAX), [1, 2, 3]);
+        assert_eq!(rotate_right([1, 2, 3], usize::MAX - 1), [2, 3, 1]);
+        assert_eq!(rotate_right([1, 2, 3, 4], usize::MAX), [2, 3, 4, 1]);
+        assert_eq!(rotate_left::<u8, 0>([], 5), []);
+        assert_eq!(rotate_right::<u8, 0>([], 5), []);
+
+        for k in 0..10 {
+            assert_eq!(rotate_right(rotate_left([1, 2, 3, 4], k), k), [1, 2, 3, 4]);
+        }
+    }
+
+    #[test]
+    fn compile_time() {
+        assert_eq!(
+            rotate_left_const::<2, _, 5>([1, 2, 3, 4, 5]),
+            rotate_left([1, 2, 3, 4, 5], 2)
+        );
+        assert_eq!(
+            rotate_left_const::<{ usize::MAX }, _, 3>([1, 2, 3]),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            rotate_right_const::<7, _, 5>([1, 2, 3, 4, 5]),
+            rotate_right([1, 2, 3, 4, 5], 7)
+        );
+        assert_eq!(rotate_right_const::<{ usize::MAX }, _, 2>([1, 2]), [2, 1]);
+        assert_eq!(rotate_left_const::<1, u8, 0>([]), []);
+        assert_eq!(rotate_right_const::<1, u8, 0>([]), []);
+
+        const TABLE: [u8; 3] =
+            rotate_right_const::<1, _, 3>(rotate_left_const::<2, _, 3>([1, 2, 3]));
+        assert_eq!(TABLE, [2, 3, 1]);
+    }
+}
@@ -0,0 +1,408 @@
+//! Searching sized arrays for elements and sub-arrays.
+
+/// Find the index of the first occurrence of `needle` in `haystack`.
+///
+/// Returns [`None`] if `needle` does not occur in `haystack`. An empty `needle` occurs at index
+/// `0`.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::find_subarray;
+///
+/// // Locate the sync word in a received frame.
+/// let frame = [0x00, 0x7E, 0x1A, 0xCF, 0xFC, 0x1D, 0x42];
+/// assert_eq!(find_subarray(&frame, &[0x1A, 0xCF, 0xFC, 0x1D]), Some(2));
+/// assert_eq!(find_subarray(&frame, &[0x1D, 0x1A]), None);
+/// ```
+pub fn find_subarray<T, const SIZE: usize, const NEEDLE: usize>(
+    haystack: &[T; SIZE],
+    needle: &[T; NEEDLE],
+) -> Option<usize>
+where
+    T: PartialEq,
+{
+    (0..=SIZE.checked_sub(NEEDLE)?).find(|&i| haystack[i..i + NEEDLE] == needle[..])
+}
+
+/// Find the index of the last occurrence of `needle` in `haystack`.
+///
+/// Returns [`None`] if `needle` does not occur in `haystack`. An empty `needle` occurs at index
+/// `SIZE`.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::rfind_subarray;
+///
+/// assert_eq!(rfind_subarray(&[1, 2, 1, 2, 3], &[1, 2]), Some(2));
+/// assert_eq!(rfind_subarray(&[1, 2, 1, 2, 3], &[2, 2]), None);
+/// ```
+pub fn rfind_subarray<T, const SIZE: usize, const NEEDLE: usize>(
+    haystack: &[T; SIZE],
+    needle: &[T; NEEDLE],
+) -> Option<usize>
+where
+    T: PartialEq,
+{
+    (0..=SIZE.checked_sub(NEEDLE)?)
+        .rev()
+        .find(|&i| haystack[i..i + NEEDLE] == needle[..])
+}
+
+/// Count the non-overlapping occurrences of `needle` in `haystack`.
+///
+/// Occurrences are counted from the start of `haystack`, just like with
+/// [`str::matches`](str::matches). An empty `needle` occurs at every index from `0` up to and
+/// including `SIZE`.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::count_occurrences;
+///
+/// assert_eq!(count_occurrences(&[1, 2, 1, 2, 1], &[1, 2]), 2);
+/// assert_eq!(count_occurrences(&[0, 0, 0, 0, 0], &[0, 0]), 2);
+/// ```
+pub fn count_occurrences<T, const SIZE: usize, const NEEDLE: usize>(
+    haystack: &[T; SIZE],
+    needle: &[T; NEEDLE],
+) -> usize
+where
+    T: PartialEq,
+{
+    let mut count = 0;
+    let mut i = 0;
+    while i + NEEDLE <= SIZE {
+        if haystack[i..i + NEEDLE] == needle[..] {
+            count += 1;
+            i += NEEDLE.max(1);
+        } else {
+            i += 1;
+        }
+    }
+    count
+}
+
+/// Find the index of the first element for which `predicate` returns `true`.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::position;
+///
+/// let readings = [12, 14, 31, 29, 15];
+/// assert_eq!(position(&readings, |&reading| reading > 25), Some(2));
+/// assert_eq!(position(&readings, |&reading| reading > 50), None);
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `predicate` panics.
+pub fn position<T, F, const SIZE: usize>(array: &[T; SIZE], predicate: F) -> Option<usize>
+where
+    F: FnMut(&T) -> bool,
+{
+    array.iter().position(predicate)
+}
+
+/// Search a sorted array for `value`.
+///
+/// Returns [`Ok`] with the index of a matching element, or [`Err`] with the index where `value`
+/// could be inserted while keeping the array sorted. If there are multiple matching elements, any
+/// one of them may be returned. If the array is not sorted, the result is unspecified, but this
+/// never panics.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::binary_search;
+///
+/// let thresholds = [10, 20, 40, 80];
+/// assert_eq!(binary_search(&thresholds, &40), Ok(2));
+/// assert_eq!(binary_search(&thresholds, &50), Err(3));
+/// ```
+pub fn binary_search<T, const SIZE: usize>(array: &[T; SIZE], value: &T) -> Result<usize, usize>
+where
+    T: Ord,
+{
+    array.binary_search(value)
+}
+
+macro_rules! const_search {
+    ($($element:ty: $find:ident, $rfind:ident, $count:ident, $binary_search:ident;)*) => {$(
+        #[doc = concat!(
+            "Find the index of the first occurrence of `needle` in `haystack` of [`",
+            stringify!($element), "`] in a `const` context."
+        )]
+        ///
+        /// Works the same as [`find_subarray`](crate::find_subarray).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use array_utils::", stringify!($find), ";")]
+        ///
+        #[doc = concat!(
+            "const INDEX: Option<usize> = ",
+            stringify!($find), "(&[0, 7, 1, 7, 1], &[7, 1]);"
+        )]
+        /// assert_eq!(INDEX, Some(1));
+        /// ```
+        pub const fn $find<const SIZE: usize, const NEEDLE: usize>(
+            haystack: &[$element; SIZE],
+            needle: &[$element; NEEDLE],
+        ) -> Option<usize> {
+            let mut i = 0;
+            while i + NEEDLE <= SIZE {
+                let mut j = 0;
+                while j < NEEDLE && haystack[i + j] == needle[j] {
+                    j += 1;
+                }
+                if j == NEEDLE {
+                    return Some(i);
+                }
+                i += 1;
+            }
+            None
+        }
+
+        #[doc = concat!(
+            "Find the index of the last occurrence of `needle` in `haystack` of [`",
+            stringify!($element), "`] in a `const` context."
+        )]
+        ///
+        /// Works the same as [`rfind_subarray`](crate::rfind_subarray).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use array_utils::", stringify!($rfind), ";")]
+        ///
+        #[doc = concat!(
+            "const INDEX: Option<usize> = ",
+            stringify!($rfind), "(&[0, 7, 1, 7, 1], &[7, 1]);"
+        )]
+        /// assert_eq!(INDEX, Some(3));
+        /// ```
+        pub const fn $rfind<const SIZE: usize, const NEEDLE: usize>(
+            haystack: &[$element; SIZE],
+            needle: &[$element; NEEDLE],
+        ) -> Option<usize> {
+            if NEEDLE > SIZE {
+                return None;
+            }
+
+            let mut i = SIZE - NEEDLE + 1;
+            while i > 0 {
+                i -= 1;
+                let mut j = 0;
+                while j < NEEDLE && haystack[i + j] == needle[j] {
+                    j += 1;
+                }
+                if j == NEEDLE {
+                    return Some(i);
+                }
+            }
+            None
+        }
+
+        #[doc = concat!(
+            "Count the non-overlapping occurrences of `needle` in `haystack` of [`",
+            stringify!($element), "`] in a `const` context."
+        )]
+        ///
+        /// Works the same as [`count_occurrences`](crate::count_occurrences).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use array_utils::", stringify!($count), ";")]
+        ///
+        #[doc = concat!(
+            "const COUNT: usize = ",
+            stringify!($count), "(&[0, 7, 1, 7, 1], &[7, 1]);"
+        )]
+        /// assert_eq!(COUNT, 2);
+        /// ```
+        pub const fn $count<const SIZE: usize, const NEEDLE: usize>(
+            haystack: &[$element; SIZE],
+            needle: &[$element; NEEDLE],
+        ) -> usize {
+            let mut count = 0;
+            let mut i = 0;
+            while i + NEEDLE <= SIZE {
+                let mut j = 0;
+                while j < NEEDLE && haystack[i + j] == needle[j] {
+                    j += 1;
+                }
+                if j == NEEDLE {
+                    count += 1;
+                    i += if NEEDLE == 0 { 1 } else { NEEDLE };
+                } else {
+                    i += 1;
+                }
+            }
+            count
+        }
+
+        #[doc = concat!(
+            "Search a sorted array of [`", stringify!($element), "`] for `value` in a `const` ",
+            "context."
+        )]
+        ///
+        /// Works the same as [`binary_search`](crate::binary_search), but takes `value` by value.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use array_utils::", stringify!($binary_search), ";")]
+        ///
+        #[doc = concat!(
+            "const INDEX: Result<usize, usize> = ",
+            stringify!($binary_search), "(&[10, 20, 40, 80], 50);"
+        )]
+        /// assert_eq!(INDEX, Err(3));
+        /// ```
+        pub const fn $binary_search<const SIZE: usize>(
+            array: &[$element; SIZE],
+            value: $element,
+        ) -> Result<usize, usize> {
+            let (mut low, mut high) = (0, SIZE);
+            while low < high {
+                let middle = low + (high - low) / 2;
+                if array[middle] == value {
+                    return Ok(middle);
+                } else if array[middle] < value {
+                    low = middle + 1;
+                } else {
+                    high = middle;
+                }
+            }
+            Err(low)
+        }
+    )*};
+}
+
+const_search! {
+    u8: find_subarray_u8, rfind_subarray_u8, count_occurrences_u8, binary_search_u8;
+    u16: find_subarray_u16, rfind_subarray_u16, count_occurrences_u16, binary_search_u16;
+    u32: find_subarray_u32, rfind_subarray_u32, count_occurrences_u32, binary_search_u32;
+    u64: find_subarray_u64, rfind_subarray_u64, count_occurrences_u64, binary_search_u64;
+    usize: find_subarray_usize, rfind_subarray_usize, count_occurrences_usize, binary_search_usize;
+    i8: find_subarray_i8, rfind_subarray_i8, count_occurrences_i8, binary_search_i8;
+    i16: find_subarray_i16, rfind_subarray_i16, count_occurrences_i16, binary_search_i16;
+    i32: find_subarray_i32, rfind_subarray_i32, count_occurrences_i32, binary_search_i32;
+    i64: find_subarray_i64, rfind_subarray_i64, count_occurrences_i64, binary_search_i64;
+    isize: find_subarray_isize, rfind_subarray_isize, count_occurrences_isize, binary_search_isize;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every array of `SIZE` elements from `0..BASE`.
+    fn all_arrays<const SIZE: usize, const BASE: u8>() -> impl Iterator<Item = [u8; SIZE]> {
+        (0..(BASE as usize).pow(SIZE as u32)).map(|mut number| {
+            ::core::array::from_fn(|_| {
+                let digit = number % BASE as usize;
+                number /= BASE as usize;
+                digit as u8
+            })
+        })
+    }
+
+    #[test]
+    fn subarrays() {
+        assert_eq!(find_subarray(&[1, 2, 3], &[]), Some(0));
+        assert_eq!(find_subarray(&[1, 2, 3], &[1, 2, 3]), Some(0));
+        assert_eq!(find_subarray(&[1, 2, 3], &[1, 2, 3, 4]), None);
+        assert_eq!(find_subarray::<u8, 0, 1>(&[], &[1]), None);
+
+        assert_eq!(rfind_subarray(&[1, 2, 3], &[]), Some(3));
+        assert_eq!(rfind_subarray(&[3, 3, 3], &[3, 3]), Some(1));
+        assert_eq!(rfind_subarray(&[1, 2], &[1, 2, 3]), None);
+
+        assert_eq!(count_occurrences(&[1, 2, 3], &[]), 4);
+        assert_eq!(count_occurrences(&[1, 2, 3], &[4]), 0);
+        assert_eq!(count_occurrences(&[1, 2], &[1, 2, 3]), 0);
+    }
+
+    #[test]
+    fn sync_word() {
+        let mut frame = [0u8; 256];
+        frame[200..204].copy_from_slice(&[0x1A, 0xCF, 0xFC, 0x1D]);
+        let sync = [0x1A, 0xCF, 0xFC, 0x1D];
+
+        assert_eq!(find_subarray(&frame, &sync), Some(200));
+        assert_eq!(find_subarray_u8(&frame, &sync), Some(200));
+        assert_eq!(count_occurrences_u8(&frame, &sync), 1);
+    }
+
+    #[test]
+    fn const_matches_generic() {
+        for haystack in all_arrays::<6, 2>() {
+            for needle in all_arrays::<2, 2>() {
+                assert_eq!(
+                    find_subarray_u8(&haystack, &needle),
+                    find_subarray(&haystack, &needle)
+                );
+                assert_eq!(
+                    rfind_subarray_u8(&haystack, &needle),
+                    rfind_subarray(&haystack, &needle)
+                );
+                assert_eq!(
+                    count_occurrences_u8(&haystack, &needle),
+                    count_occurrences(&haystack, &needle)
+                );
+            }
+
+            assert_eq!(
+                find_subarray_u8(&haystack, &[]),
+                find_subarray(&haystack, &[])
+            );
+            assert_eq!(
+                rfind_subarray_u8(&haystack, &[]),
+                rfind_subarray(&haystack, &[])
+            );
+            assert_eq!(
+                count_occurrences_u8(&haystack, &[]),
+                count_occurrences(&haystack, &[])
+            );
+            assert_eq!(rfind_subarray_u8(&haystack, &[0; 7]), None);
+        }
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(position(&[1, 2, 3], |&value| value % 2 == 0), Some(1));
+        assert_eq!(position::<u8, _, 0>(&[], |_| true), None);
+    }
+
+    #[test]
+    fn binary_searching() {
+        let sorted = [1, 3, 3, 5, 8];
+        for value in 0..10 {
+            let expected = binary_search(&sorted, &value);
+            assert_eq!(binary_search_i32(&sorted, value).is_ok(), expected.is_ok());
+            match binary_search_i32(&sorted, value) {
+                Ok(index) => assert_eq!(sorted[index], value),
+                Err(index) => assert_eq!(Err(index), expected),
+            }
+        }
+
+        assert_eq!(binary_search_u8(&[], 1), Err(0));
+        assert_eq!(binary_search_u8(&[u8::MAX], u8::MAX), Ok(0));
+        assert_eq!(binary_search_i64(&[i64::MIN, 0], i64::MIN), Ok(0));
+        assert_eq!(binary_search_usize(&[1, 2], usize::MAX), Err(2));
+    }
+
+    #[test]
+    fn const_contexts() {
+        const FRAME: [u8; 8] = [0, 0, 0xAA, 0x55, 1, 0xAA, 0x55, 2];
+        const FIRST: Option<usize> = find_subarray_u8(&FRAME, &[0xAA, 0x55]);
+        const LAST: Option<usize> = rfind_subarray_u8(&FRAME, &[0xAA, 0x55]);
+        const COUNT: usize = count_occurrences_u8(&FRAME, &[0xAA, 0x55]);
+        assert_eq!((FIRST, LAST, COUNT), (Some(2), Some(5), 2));
+    }
+}
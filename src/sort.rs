@@ -0,0 +1,242 @@
+//! Sorting and ordering small sized arrays.
+
+/// Sort an array, returning the sorted array.
+///
+/// The sort is unstable, meaning that equal elements may be reordered. It does not allocate.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::sorted;
+///
+/// assert_eq!(sorted([4, 1, 3, 2]), [1, 2, 3, 4]);
+///
+/// // Median of a window of sensor readings.
+/// let readings = [21, 87, 20, 22, 19];
+/// assert_eq!(sorted(readings)[readings.len() / 2], 21);
+/// ```
+pub fn sorted<T, const SIZE: usize>(mut array: [T; SIZE]) -> [T; SIZE]
+where
+    T: Ord,
+{
+    array.sort_unstable();
+    array
+}
+
+/// Sort an array by a key extracted from every element, returning the sorted array.
+///
+/// The sort is stable, meaning that equal elements keep their order. It is an insertion sort,
+/// which is fast for small arrays but calls `f` twice for every comparison.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::sorted_by_key;
+///
+/// let tasks = [("flush", 2), ("read", 0), ("write", 1), ("sync", 0)];
+/// assert_eq!(
+///     sorted_by_key(tasks, |&(_, priority)| priority),
+///     [("read", 0), ("sync", 0), ("write", 1), ("flush", 2)]
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Only panics if the given `f` panics.
+pub fn sorted_by_key<T, K, F, const SIZE: usize>(mut array: [T; SIZE], mut f: F) -> [T; SIZE]
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    for i in 1..SIZE {
+        let mut j = i;
+        while j > 0 && f(&array[j - 1]) > f(&array[j]) {
+            array.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+    array
+}
+
+/// Check whether the elements of an array are in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::is_sorted;
+///
+/// assert!(is_sorted(&[1, 2, 2, 3]));
+/// assert!(!is_sorted(&[1, 3, 2]));
+/// assert!(is_sorted::<u8, 0>(&[]));
+/// ```
+pub fn is_sorted<T, const SIZE: usize>(array: &[T; SIZE]) -> bool
+where
+    T: PartialOrd,
+{
+    array.windows(2).all(|pair| pair[0] <= pair[1])
+}
+
+/// Determine the indices that would sort an array.
+///
+/// The element at index `argsort(array)[i]` of `array` is the `i`-th smallest element. Equal
+/// elements keep their order, so the indices of equal elements are ascending.
+///
+/// # Examples
+///
+/// ```
+/// use array_utils::argsort;
+///
+/// let priorities = [30, 10, 20, 10];
+/// assert_eq!(argsort(&priorities), [1, 3, 2, 0]);
+/// ```
+pub fn argsort<T, const SIZE: usize>(array: &[T; SIZE]) -> [usize; SIZE]
+where
+    T: Ord,
+{
+    let indices = ::core::array::from_fn(|index| index);
+    sorted_by_key(indices, |&index| &array[index])
+}
+
+macro_rules! sorting_networks {
+    ($($name:ident: $element:ty,)*) => {$(
+        #[doc = concat!("Sort an array of [`", stringify!($element), "`] in a `const` context.")]
+        ///
+        /// Uses Batcher's odd-even merge sorting network, which needs `O(SIZE log² SIZE)`
+        /// comparisons and no comparison trait calls, so it can be evaluated at compile time. For
+        /// sorting at runtime, [`sorted`](crate::sorted) is usually faster.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use array_utils::", stringify!($name), ";")]
+        ///
+        #[doc = concat!(
+            "const TABLE: [", stringify!($element), "; 6] = ",
+            stringify!($name), "([9, 3, 7, 1, 3, 0]);"
+        )]
+        /// assert_eq!(TABLE, [0, 1, 3, 3, 7, 9]);
+        /// ```
+        pub const fn $name<const SIZE: usize>(mut array: [$element; SIZE]) -> [$element; SIZE] {
+            let mut p = 1;
+            while p < SIZE {
+                let mut k = p;
+                while k >= 1 {
+                    let mut j = k % p;
+                    while j + k < SIZE {
+                        let mut i = 0;
+                        while i < k && i + j + k < SIZE {
+                            let (low, high) = (i + j, i + j + k);
+                            if low / (2 * p) == high / (2 * p) && array[low] > array[high] {
+                                let swapped = array[low];
+                                array[low] = array[high];
+                                array[high] = swapped;
+                            }
+                            i += 1;
+                        }
+                        j += 2 * k;
+                    }
+                    k /= 2;
+                }
+                p *= 2;
+            }
+            array
+        }
+    )*};
+}
+
+sorting_networks! {
+    sorted_u8: u8,
+    sorted_u16: u16,
+    sorted_u32: u32,
+    sorted_u64: u64,
+    sorted_usize: usize,
+    sorted_i8: i8,
+    sorted_i16: i16,
+    sorted_i32: i32,
+    sorted_i64: i64,
+    sorted_isize: isize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every array of `SIZE` elements from `0..BASE`.
+    fn all_arrays<const SIZE: usize, const BASE: u8>() -> impl Iterator<Item = [u8; SIZE]> {
+        (0..(BASE as usize).pow(SIZE as u32)).map(|mut number| {
+            ::core::array::from_fn(|_| {
+                let digit = number % BASE as usize;
+                number /= BASE as usize;
+                digit as u8
+            })
+        })
+    }
+
+    #[test]
+    fn sorting() {
+        assert_eq!(sorted([3, 1, 2]), [1, 2, 3]);
+        assert_eq!(sorted::<u8, 0>([]), []);
+        assert_eq!(
+            sorted_by_key([3, -1, -2], |value: &i32| value.abs()),
+            [-1, -2, 3]
+        );
+        assert_eq!(
+            sorted_by_key([(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')], |pair| pair.0),
+            [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(is_sorted(&[1]));
+        assert!(!is_sorted(&[2, 1]));
+        assert!(!is_sorted(&[1.0, f32::NAN]));
+        assert_eq!(argsort(&['c', 'a', 'b']), [1, 2, 0]);
+        assert_eq!(argsort(&[1, 1, 0, 1]), [2, 0, 1, 3]);
+        assert_eq!(argsort::<u8, 0>(&[]), []);
+    }
+
+    #[test]
+    fn networks() {
+        for array in all_arrays::<5, 4>() {
+            assert_eq!(sorted_u8(array), sorted(array));
+        }
+        // By the 0-1 principle, a network sorting every array of zeros and ones sorts all arrays.
+        // Beyond 20 elements, checking all of them takes too long for a unit test.
+        macro_rules! sorts_zeros_and_ones {
+            ($($size:literal)*) => {$(
+                for array in all_arrays::<$size, 2>() {
+                    let sorted = sorted_u8(array);
+                    assert!(is_sorted(&sorted));
+                    assert_eq!(sorted.iter().sum::<u8>(), array.iter().sum::<u8>());
+                }
+            )*};
+        }
+        sorts_zeros_and_ones!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20);
+        for array in all_arrays::<7, 3>() {
+            assert!(is_sorted(&sorted_u8(array)));
+            assert_eq!(sorted_u8(array), sorted(array));
+        }
+        assert_eq!(
+            sorted_i8([0, -1, i8::MIN, i8::MAX]),
+            [i8::MIN, -1, 0, i8::MAX]
+        );
+        assert_eq!(sorted_u64::<0>([]), []);
+        assert_eq!(sorted_u64([1]), [1]);
+    }
+
+    #[test]
+    fn network_sizes() {
+        let reversed: [usize; 32] = ::core::array::from_fn(|index| 31 - index);
+        assert_eq!(sorted_usize(reversed), sorted(reversed));
+
+        let scrambled: [u32; 23] = ::core::array::from_fn(|index| (index as u32 * 7919) % 23);
+        assert_eq!(sorted_u32(scrambled), sorted(scrambled));
+    }
+
+    #[test]
+    fn const_contexts() {
+        const MEDIAN: i32 = sorted_i32([5, -3, 9, 0, 2])[2];
+        assert_eq!(MEDIAN, 2);
+    }
+}
@@ -0,0 +1,18 @@
+//! Fixtures shared by the unit tests of the different modules.
+
+use core::cell::Cell;
+
+/// Non-[`Copy`] element which counts how many times it has been dropped.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Tracked<'a>(pub(crate) usize, pub(crate) &'a Cell<usize>);
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+/// An array of [`Tracked`] elements holding their own index.
+pub(crate) fn tracked<const SIZE: usize>(drops: &Cell<usize>) -> [Tracked<'_>; SIZE] {
+    ::core::array::from_fn(|index| Tracked(index, drops))
+}
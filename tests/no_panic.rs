@@ -0,0 +1,356 @@
+//! Property based tests which check that no utility panics, whatever indices are given.
+
+use array_utils::*;
+use proptest::prelude::*;
+
+fn index() -> impl Strategy<Value = usize> {
+    prop_oneof![0..16usize, any::<usize>()]
+}
+
+/// Reference model of the drifting functions, working with signed offsets.
+#[allow(clippy::needless_range_loop)]
+fn drift_model<const SIZE: usize>(
+    array: [u8; SIZE],
+    range: (usize, usize),
+    at: i128,
+) -> [u8; SIZE] {
+    let mut buffer = [0xFF; SIZE];
+    for i in range.0..range.1.min(SIZE) {
+        let target = at + (i - range.0) as i128;
+        if (0..SIZE as i128).contains(&target) {
+            buffer[target as usize] = array[i];
+        }
+    }
+    buffer
+}
+
+proptest! {
+    #[test]
+    #[cfg(feature = "drift")]
+    fn drift_to_end_never_panics(array: [u8; 8], till in index(), margin in index()) {
+        let drifted = drift_to_end(array, till, margin, 0xFF);
+
+        let till = till.min(8);
+        let at = 8 - margin.min(9) as i128 - till as i128;
+        prop_assert_eq!(drifted, drift_model(array, (0, till), at));
+        prop_assert_eq!(drift_to_end_with(array, till, margin, || 0xFF), drifted);
+
+        let mut in_place = array;
+        drift_to_end_in_place(&mut in_place, till, margin, 0xFF);
+        prop_assert_eq!(in_place, drifted);
+    }
+
+    #[test]
+    #[cfg(feature = "drift")]
+    fn drift_to_begin_never_panics(array: [u8; 8], from in index(), margin in index()) {
+        let drifted = drift_to_begin(array, from, margin, 0xFF);
+
+        prop_assert_eq!(drifted, drift_model(array, (from, 8), margin.min(8) as i128));
+        prop_assert_eq!(drift_to_begin_with(array, from, margin, || 0xFF), drifted);
+
+        let mut in_place = array;
+        drift_to_begin_in_place(&mut in_place, from, margin, 0xFF);
+        prop_assert_eq!(in_place, drifted);
+    }
+
+    #[test]
+    #[cfg(feature = "superimpose")]
+    fn superimpose_never_panics(main: [u8; 8], sub: [u8; 3], starting_from in index()) {
+        let superimposed = superimpose(main, sub, starting_from);
+
+        for (i, element) in superimposed.iter().enumerate() {
+            match i.checked_sub(starting_from) {
+                Some(offset) if offset < 3 => prop_assert_eq!(*element, sub[offset]),
+                _ => prop_assert_eq!(*element, main[i]),
+            }
+        }
+        prop_assert_eq!(superimpose_move(main, sub, starting_from), superimposed);
+
+        let mut in_place = main;
+        superimpose_in_place(&mut in_place, &sub, starting_from);
+        prop_assert_eq!(in_place, superimposed);
+        if let Ok(checked) = try_superimpose(main, sub, starting_from) {
+            prop_assert_eq!(checked, superimposed);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "slice")]
+    fn sized_slice_never_panics(original: [u8; 8], from in index(), till in index()) {
+        let slice: [u8; 5] = sized_slice(original, from, till, 0xFF);
+
+        for (i, element) in slice.iter().enumerate() {
+            match from.checked_add(i) {
+                Some(index) if index < till.min(8) => prop_assert_eq!(*element, original[index]),
+                _ => prop_assert_eq!(*element, 0xFF),
+            }
+        }
+        prop_assert_eq!(sized_slice_with(original, from, till, || 0xFF), slice);
+        if let Ok(checked) = try_sized_slice(original, from, till) {
+            prop_assert_eq!(checked, slice);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn sized_slice_2d_never_panics(
+        original: [[u8; 5]; 4],
+        row in index(),
+        column in index(),
+    ) {
+        let slice: [[u8; 3]; 2] = sized_slice_2d(original, (row, column), 0xFF);
+
+        for (i, slice_row) in slice.iter().enumerate() {
+            for (j, element) in slice_row.iter().enumerate() {
+                match (row.checked_add(i), column.checked_add(j)) {
+                    (Some(r), Some(c)) if r < 4 && c < 5 => {
+                        prop_assert_eq!(*element, original[r][c])
+                    }
+                    _ => prop_assert_eq!(*element, 0xFF),
+                }
+            }
+        }
+
+        let empty: [[u8; 0]; 2] = sized_slice_2d(original, (row, column), 0xFF);
+        prop_assert_eq!(empty, [[]; 2]);
+        let from_empty: [[u8; 2]; 2] = sized_slice_2d([[0u8; 0]; 3], (row, column), 0xFF);
+        prop_assert_eq!(from_empty, [[0xFF; 2]; 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn superimpose_2d_never_panics(
+        main: [[u8; 5]; 4],
+        sub: [[u8; 3]; 2],
+        row in index(),
+        column in index(),
+    ) {
+        let superimposed = superimpose_2d(main, sub, (row, column));
+
+        for (i, main_row) in superimposed.iter().enumerate() {
+            for (j, element) in main_row.iter().enumerate() {
+                match (i.checked_sub(row), j.checked_sub(column)) {
+                    (Some(r), Some(c)) if r < 2 && c < 3 => prop_assert_eq!(*element, sub[r][c]),
+                    _ => prop_assert_eq!(*element, main[i][j]),
+                }
+            }
+        }
+
+        prop_assert_eq!(superimpose_2d(main, [[0u8; 0]; 2], (row, column)), main);
+        prop_assert_eq!(superimpose_2d([[0u8; 0]; 3], sub, (row, column)), [[]; 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "insert")]
+    fn insert_at_never_panics(array: [u8; 8], index in index(), value: u8) {
+        let (inserted, pushed_off) = insert_at(array, index, value);
+
+        if index < 8 {
+            let mut model = array[..index].to_vec();
+            model.push(value);
+            model.extend_from_slice(&array[index..7]);
+            prop_assert_eq!(&inserted[..], &model[..]);
+            prop_assert_eq!(pushed_off, Some(array[7]));
+        } else {
+            prop_assert_eq!(inserted, array);
+            prop_assert_eq!(pushed_off, Some(value));
+        }
+        prop_assert_eq!(insert_at([0u8; 0], index, value), ([], Some(value)));
+    }
+
+    #[test]
+    #[cfg(feature = "insert")]
+    fn remove_at_never_panics(array: [u8; 8], index in index()) {
+        let (removed_from, removed) = remove_at(array, index, 0xFF);
+
+        if index < 8 {
+            let mut model = array.to_vec();
+            model.remove(index);
+            model.push(0xFF);
+            prop_assert_eq!(&removed_from[..], &model[..]);
+            prop_assert_eq!(removed, array[index]);
+        } else {
+            prop_assert_eq!(removed_from, array);
+            prop_assert_eq!(removed, 0xFF);
+        }
+        prop_assert_eq!(remove_at([0u8; 0], index, 0xFF), ([], 0xFF));
+    }
+
+    #[test]
+    #[cfg(feature = "insert")]
+    fn insert_slice_at_never_panics(
+        array: [u8; 8],
+        index in index(),
+        slice in proptest::collection::vec(any::<u8>(), 0..12),
+    ) {
+        let inserted = insert_slice_at(array, index, &slice);
+
+        let mut model = array.to_vec();
+        if index < 8 {
+            model.splice(index..index, slice.iter().copied());
+        }
+        prop_assert_eq!(&inserted[..], &model[..8]);
+        prop_assert_eq!(insert_slice_at([0u8; 0], index, &slice), []);
+    }
+
+    #[test]
+    #[cfg(feature = "insert")]
+    fn remove_range_never_panics(array: [u8; 8], from in index(), till in index()) {
+        let removed = remove_range(array, from, till, 0xFF);
+
+        let mut model = array.to_vec();
+        if from < till && from < 8 {
+            model.drain(from..till.min(8));
+            model.resize(8, 0xFF);
+        }
+        prop_assert_eq!(&removed[..], &model[..]);
+        prop_assert_eq!(remove_range([0u8; 0], from, till, 0xFF), []);
+    }
+
+    #[test]
+    #[cfg(feature = "rotate")]
+    fn rotate_never_panics(array: [u8; 8], k in index()) {
+        let mut model = array;
+        model.rotate_left(k % 8);
+        prop_assert_eq!(rotate_left(array, k), model);
+        prop_assert_eq!(rotate_right(model, k), array);
+        prop_assert_eq!(rotate_left([0u8; 0], k), []);
+        prop_assert_eq!(rotate_right([0u8; 0], k), []);
+    }
+
+    #[test]
+    #[cfg(feature = "search")]
+    fn search_never_panics(
+        haystack in proptest::array::uniform8(0..3u8),
+        needle in proptest::array::uniform2(0..3u8),
+        value in index(),
+    ) {
+        // A small alphabet makes matches likely; the string methods serve as the model.
+        let text: String = haystack.iter().map(|&b| char::from(b'a' + b)).collect();
+        let pattern: String = needle.iter().map(|&b| char::from(b'a' + b)).collect();
+
+        prop_assert_eq!(find_subarray(&haystack, &needle), text.find(&pattern));
+        prop_assert_eq!(rfind_subarray(&haystack, &needle), text.rfind(&pattern));
+        prop_assert_eq!(count_occurrences(&haystack, &needle), text.matches(&pattern).count());
+        prop_assert_eq!(find_subarray_u8(&haystack, &needle), text.find(&pattern));
+        prop_assert_eq!(rfind_subarray_u8(&haystack, &needle), text.rfind(&pattern));
+        prop_assert_eq!(count_occurrences_u8(&haystack, &needle), text.matches(&pattern).count());
+        prop_assert_eq!(find_subarray(&needle, &haystack), None);
+        prop_assert_eq!(count_occurrences_u8(&needle, &haystack), 0);
+
+        let mut sorted = haystack.map(usize::from);
+        sorted.sort_unstable();
+        let found = binary_search_usize(&sorted, value);
+        match found {
+            Ok(i) => prop_assert_eq!(sorted[i], value),
+            Err(i) => prop_assert_eq!(i, sorted.iter().filter(|&&element| element < value).count()),
+        }
+        prop_assert_eq!(binary_search(&sorted, &value).is_ok(), found.is_ok());
+        prop_assert_eq!(binary_search_usize(&[], value), Err(0));
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn extract_never_panics(grid: [[u8; 5]; 4], row in index(), column in index()) {
+        let extracted_row = extract_row(grid, row, 0xFF);
+        let extracted_column = extract_column(grid, column, 0xFF);
+
+        prop_assert_eq!(extracted_row, grid.get(row).copied().unwrap_or([0xFF; 5]));
+        for (i, element) in extracted_column.iter().enumerate() {
+            prop_assert_eq!(*element, grid[i].get(column).copied().unwrap_or(0xFF));
+        }
+        prop_assert_eq!(extract_row([[0u8; 5]; 0], row, 0xFF), [0xFF; 5]);
+        prop_assert_eq!(extract_column([[0u8; 0]; 4], column, 0xFF), [0xFF; 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "interleave")]
+    fn interleave_never_panics(channels: [[u8; 3]; 2], samples: [u8; 5]) {
+        let truncated: [u8; 4] = interleave(channels, 0xFF);
+        let filled: [u8; 8] = interleave(channels, 0xFF);
+        for (i, element) in filled.iter().enumerate() {
+            let expected = if i < 6 { channels[i % 2][i / 2] } else { 0xFF };
+            prop_assert_eq!(*element, expected);
+        }
+        prop_assert_eq!(&truncated[..], &filled[..4]);
+
+        let split: [[u8; 3]; 2] = deinterleave(samples, 0xFF);
+        for (channel, split_channel) in split.iter().enumerate() {
+            for (sample, element) in split_channel.iter().enumerate() {
+                let expected = samples.get(sample * 2 + channel).copied().unwrap_or(0xFF);
+                prop_assert_eq!(*element, expected);
+            }
+        }
+        prop_assert_eq!(deinterleave::<u8, 6, 3, 2>(interleave(channels, 0xFF), 0xFF), channels);
+        prop_assert_eq!(interleave::<u8, 3, 0, 4>([], 0xFF), [0xFF; 4]);
+        prop_assert_eq!(deinterleave::<u8, 5, 0, 2>(samples, 0xFF), [[]; 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "bits")]
+    fn bit_array_never_panics(words: [u32; 2], index in prop_oneof![0..80usize, any::<usize>()]) {
+        let mut bits = BitArray::from_words(words);
+        let was_set = bits.test(index);
+        prop_assert_eq!(was_set, index < 64 && words[index / 32] & 1 << (index % 32) != 0);
+
+        bits.toggle(index);
+        prop_assert_eq!(bits.test(index), index < 64 && !was_set);
+        bits.toggle(index);
+        prop_assert_eq!(bits.into_words(), words);
+
+        bits.set(index);
+        prop_assert_eq!(bits.test(index), index < 64);
+        let ones: usize = words.iter().map(|word| word.count_ones() as usize).sum();
+        prop_assert_eq!(bits.count_ones(), ones + usize::from(index < 64 && !was_set));
+
+        let mut empty = BitArray::<0>::new();
+        empty.set(index);
+        empty.toggle(index);
+        prop_assert!(!empty.test(index));
+    }
+
+    #[test]
+    #[cfg(feature = "vec")]
+    fn array_vec_never_panics(
+        values in proptest::collection::vec(any::<u8>(), 0..=8),
+        index in index(),
+        value: u8,
+    ) {
+        let mut vec: ArrayVec<u8, 8> = values.iter().copied().collect();
+        let mut model = values.clone();
+
+        let inserted = vec.insert(index, value);
+        if model.len() < 8 && index <= model.len() {
+            model.insert(index, value);
+            prop_assert_eq!(inserted, Ok(()));
+        } else {
+            prop_assert_eq!(inserted, Err(value));
+        }
+        prop_assert_eq!(vec.as_slice(), &model[..]);
+
+        let removed = vec.remove(index);
+        prop_assert_eq!(removed, (index < model.len()).then(|| model.remove(index)));
+        prop_assert_eq!(vec.as_slice(), &model[..]);
+
+        let mut empty = ArrayVec::<u8, 0>::new();
+        prop_assert_eq!(empty.insert(index, value), Err(value));
+        prop_assert_eq!(empty.remove(index), None);
+    }
+
+    #[test]
+    #[cfg(feature = "ring")]
+    fn ring_buffer_never_panics(
+        values in proptest::collection::vec(any::<u8>(), 0..20),
+        index in index(),
+    ) {
+        let mut ring = RingBuffer::<u8, 8>::new();
+        for &value in &values {
+            ring.push(value);
+        }
+
+        let newest = &values[values.len().saturating_sub(8)..];
+        prop_assert_eq!(ring.get(index), newest.get(index));
+        let empty = RingBuffer::<u8, 0>::new();
+        prop_assert_eq!(empty.get(index), None);
+    }
+}